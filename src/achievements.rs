@@ -0,0 +1,266 @@
+use bevy::{prelude::*, scene::ron::de::from_str, utils::hashbrown::HashSet};
+use serde::{Deserialize, Serialize};
+use std::io::Result;
+
+use crate::{
+    game::{load_power_definitions, GameEconomy, PowerDefinitionsReloaded},
+    save::{format_load, format_save, Saveable},
+    ui::{AchievementListNode, Pallette, Toast, UIButton, UIButtonChildNode, UIButtonParentNode},
+    unlock::UnlockCondition,
+    AppState,
+};
+
+pub struct AchievementPlugin;
+impl Plugin for AchievementPlugin {
+    fn name(&self) -> &str {
+        "Achievement Plugin"
+    }
+
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::Achievements), startup)
+            .add_systems(OnExit(AppState::Achievements), cleanup)
+            .add_systems(
+                Update,
+                (achievement_button_interaction, escape_to_menu)
+                    .run_if(in_state(AppState::Achievements)),
+            )
+            .add_systems(
+                Update,
+                check_achievements.run_if(in_state(AppState::Playing)),
+            )
+            .add_systems(
+                OnExit(AppState::Loading),
+                validate_achievements.after(load_power_definitions),
+            )
+            .add_systems(
+                Update,
+                validate_achievements.run_if(on_event::<PowerDefinitionsReloaded>),
+            )
+            .insert_resource(AchievementDefinitions::from_ron(include_str!(
+                "../assets/data/achievements.ron"
+            )))
+            .insert_resource(Achievements::load("achievements.ron").unwrap_or_default());
+    }
+}
+
+#[derive(Component)]
+struct CleanupAchievementMenu;
+
+#[derive(Component)]
+enum AchievementMenuButton {
+    Back,
+}
+
+#[derive(Deserialize)]
+struct Achievement {
+    id: String,
+    title: String,
+    description: String,
+    condition: UnlockCondition,
+}
+
+/// Every achievement defined in `assets/data/achievements.ron`.
+#[derive(Default, Resource)]
+struct AchievementDefinitions(Vec<Achievement>);
+impl AchievementDefinitions {
+    fn from_ron(s: &str) -> Self {
+        match from_str::<Vec<Achievement>>(s) {
+            Ok(achievements) => {
+                info!("[LOADED] Achievements: {}", achievements.len());
+                Self(achievements)
+            }
+            Err(e) => {
+                info!("[ERROR] Achievements Failed To Parse: {}", e);
+                Self::default()
+            }
+        }
+    }
+}
+
+/// Achievements earned so far.
+#[derive(Default, Deserialize, Resource, Serialize)]
+#[serde(default)]
+pub struct Achievements {
+    unlocked: HashSet<String>,
+    // SAVES FROM BEFORE STATISTICS COUNTED CLICKS HERE, NEVER WRITTEN BACK
+    #[serde(rename = "clicks", skip_serializing)]
+    legacy_clicks: u64,
+}
+impl Achievements {
+    pub fn unlocked(&self) -> &HashSet<String> {
+        &self.unlocked
+    }
+
+    pub fn legacy_clicks(&self) -> u64 {
+        self.legacy_clicks
+    }
+}
+impl Saveable for Achievements {
+    fn save(&self, filename: &str) -> Result<()> {
+        format_save(self, filename)
+    }
+
+    fn load(filename: &str) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        format_load(filename)
+    }
+}
+
+fn check_achievements(
+    definitions: Res<AchievementDefinitions>,
+    mut achievements: ResMut<Achievements>,
+    economy: Res<GameEconomy>,
+    mut evw_toast: EventWriter<Toast>,
+) {
+    for achievement in definitions.0.iter() {
+        if achievements.unlocked.contains(&achievement.id) {
+            continue;
+        }
+
+        let met = economy.condition_met(&achievement.condition);
+
+        if met {
+            achievements.unlocked.insert(achievement.id.clone());
+            evw_toast.send(Toast(format!("ACHIEVEMENT: {}", achievement.title)));
+            info!("[UNLOCKED] Achievement: {}", achievement.title);
+        }
+    }
+}
+
+// AN UNKNOWN POWER NEVER COUNTS AS OWNED, SO THE ACHIEVEMENT COULD NEVER UNLOCK
+fn validate_achievements(definitions: Res<AchievementDefinitions>, economy: Res<GameEconomy>) {
+    for achievement in definitions.0.iter() {
+        for power in achievement.condition.powers() {
+            if economy.power(power).is_none() {
+                info!(
+                    "[ERROR] Achievement {} References An Unknown Power: {}",
+                    achievement.id, power
+                );
+            }
+        }
+    }
+}
+
+fn startup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    definitions: Res<AchievementDefinitions>,
+    achievements: Res<Achievements>,
+) {
+    let font = asset_server.load("fonts/PublicPixel.ttf");
+
+    let style = (
+        BorderColor(Pallette::Black.srgb()),
+        BorderRadius::all(Val::Percent(10.0)),
+        BackgroundColor(Pallette::Lighter.srgb()),
+    );
+
+    // SPAWN ACHIEVEMENT LIST
+    commands
+        .spawn((
+            AchievementListNode::node(),
+            AchievementListNode::marker(),
+            CleanupAchievementMenu,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(format!(
+                    "ACHIEVEMENTS {}/{}",
+                    achievements.unlocked.len(),
+                    definitions.0.len()
+                )),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 30.0,
+                    ..default()
+                },
+                TextColor(Pallette::Lighter.srgb()),
+            ));
+
+            for achievement in definitions.0.iter() {
+                // LOCKED ACHIEVEMENTS ARE DIMMED
+                let (mark, color) = if achievements.unlocked.contains(&achievement.id) {
+                    ("[X]", Pallette::White.srgb())
+                } else {
+                    ("[ ]", Pallette::Darker.srgb())
+                };
+                parent.spawn((
+                    Text::new(format!(
+                        "{} {} - {}",
+                        mark, achievement.title, achievement.description
+                    )),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(color),
+                ));
+            }
+        });
+
+    // SPAWN BACK BUTTON NODE
+    commands
+        .spawn((
+            UIButtonParentNode::new(100.0, 20.0, 80.0),
+            UIButtonParentNode::marker(),
+            CleanupAchievementMenu,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    UIButtonChildNode::node(),
+                    UIButtonChildNode::marker(),
+                    Button,
+                    AchievementMenuButton::Back,
+                    UIButton,
+                    style,
+                ))
+                .with_child((
+                    Text::new("BACK"),
+                    TextFont {
+                        font,
+                        font_size: 33.0,
+                        ..default()
+                    },
+                    TextColor(Pallette::Black.srgb()),
+                ));
+        });
+
+    info!("[SPAWNED] Achievement Menu Entities.");
+}
+
+fn cleanup(mut commands: Commands, query_cleanup: Query<Entity, With<CleanupAchievementMenu>>) {
+    for entity in query_cleanup.iter() {
+        commands.entity(entity).despawn_recursive();
+        info!("[DESPAWNED] Achievement Menu Entities");
+    }
+}
+
+fn achievement_button_interaction(
+    mut next_state: ResMut<NextState<AppState>>,
+    mut interaction_query: Query<
+        (&Interaction, &AchievementMenuButton),
+        (Changed<Interaction>, With<AchievementMenuButton>),
+    >,
+) {
+    for (interaction, amb) in &mut interaction_query {
+        if *interaction == Interaction::Pressed {
+            match amb {
+                AchievementMenuButton::Back => {
+                    next_state.set(AppState::Menu);
+                    info!("[MODIFIED] AppState >> Menu");
+                }
+            }
+        }
+    }
+}
+
+fn escape_to_menu(keys: Res<ButtonInput<KeyCode>>, mut next_state: ResMut<NextState<AppState>>) {
+    if keys.just_pressed(KeyCode::Escape) {
+        next_state.set(AppState::Menu);
+        info!("[MODIFIED] AppState >> Menu");
+    }
+}
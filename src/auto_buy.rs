@@ -0,0 +1,99 @@
+//! Rules the auto-buyer follows, with no Bevy in sight so tools can run them too.
+
+use serde::{Deserialize, Serialize};
+
+use crate::economy::{Economy, Power};
+
+/// Which affordable power the auto-buyer picks first.
+#[derive(Clone, Copy, Default, Deserialize, PartialEq, Serialize)]
+pub enum AutoBuyPriority {
+    #[default]
+    Cheapest,
+    // SHORTEST PRICE / PWR/S ADDED
+    BestPayback,
+    // FURTHEST ALONG THE BUTTON LIST
+    Newest,
+}
+impl AutoBuyPriority {
+    pub fn next(&self) -> Self {
+        use AutoBuyPriority::*;
+
+        match self {
+            Cheapest => BestPayback,
+            BestPayback => Newest,
+            Newest => Cheapest,
+        }
+    }
+
+    pub fn label(&self) -> &str {
+        use AutoBuyPriority::*;
+
+        match self {
+            Cheapest => "CHEAPEST",
+            BestPayback => "PAYBACK",
+            Newest => "NEWEST",
+        }
+    }
+
+    /// The power this priority picks first out of `candidates`.
+    pub fn pick<'a>(&self, candidates: impl Iterator<Item = &'a Power>) -> Option<&'a Power> {
+        use AutoBuyPriority::*;
+
+        match self {
+            Cheapest => candidates.min_by_key(|power| power.price(1)),
+            BestPayback => candidates.min_by(|a, b| payback_secs(a).total_cmp(&payback_secs(b))),
+            Newest => candidates.max_by_key(|power| power.definition().order),
+        }
+    }
+}
+
+/// What the player told the auto-buyer to do. Saved with the game.
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct AutoBuyRules {
+    pub enabled: bool,
+    // PERCENT OF THE TOTAL THE AUTO-BUYER NEVER SPENDS
+    pub reserve_percent: f64,
+    // SKIP ANYTHING THAT TAKES LONGER THAN THIS TO PAY FOR ITSELF
+    pub max_payback_secs: Option<f64>,
+    pub priority: AutoBuyPriority,
+}
+impl Default for AutoBuyRules {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            reserve_percent: 10.0,
+            max_payback_secs: None,
+            priority: AutoBuyPriority::Cheapest,
+        }
+    }
+}
+impl AutoBuyRules {
+    /// The power to buy one copy of right now, or `None` if the rules allow nothing.
+    pub fn choose<'a>(&self, economy: &'a Economy) -> Option<&'a Power> {
+        if !self.enabled {
+            return None;
+        }
+        let reserve = (self.reserve_percent / 100.0).clamp(0.0, 1.0);
+        let budget = economy.total_power() * (1.0 - reserve);
+
+        let candidates = economy.unlocked().filter(|power| {
+            power.remaining() > 0
+                && !power.marginal_power_per_second().is_zero()
+                && power.price(1) <= budget
+                && self
+                    .max_payback_secs
+                    .is_none_or(|max| payback_secs(power) <= max)
+        });
+        self.priority.pick(candidates)
+    }
+}
+
+/// Seconds one more copy of `power` takes to earn back its price, infinite if it adds nothing.
+pub fn payback_secs(power: &Power) -> f64 {
+    let added = power.marginal_power_per_second();
+    if added.is_zero() {
+        return f64::INFINITY;
+    }
+    (power.price(1) / added).to_f64()
+}
@@ -0,0 +1,379 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::{collections::VecDeque, io::Result};
+
+use crate::{
+    auto_buy::AutoBuyRules,
+    game::GameEconomy,
+    number::{clock, BigNumber},
+    save::{format_load, format_save, Save, Saveable},
+    statistics::Statistics,
+    ui::{AutomationLogNode, Pallette, UIButton, UIButtonChildNode, UIButtonParentNode},
+    upgrades::UpgradeModifiers,
+    AppState, PauseState,
+};
+
+// SECONDS BETWEEN AUTO-BUYER PASSES
+const AUTO_BUY_SECS: f32 = 0.5;
+// CAPS ONE PASS SO A HUGE BANK CANNOT STALL A FRAME
+const MAX_BUYS_PER_PASS: usize = 10;
+// PURCHASES KEPT IN THE LOG
+const LOG_LEN: usize = 12;
+// WHAT THE RULE BUTTONS CYCLE THROUGH
+const RESERVE_STEPS: [f64; 5] = [0.0, 10.0, 25.0, 50.0, 75.0];
+const PAYBACK_STEPS: [Option<f64>; 5] = [None, Some(30.0), Some(60.0), Some(300.0), Some(600.0)];
+
+pub struct AutomationPlugin;
+impl Plugin for AutomationPlugin {
+    fn name(&self) -> &str {
+        "Automation Plugin"
+    }
+
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::Automation), startup)
+            .add_systems(OnExit(AppState::Automation), cleanup)
+            .add_systems(
+                Update,
+                (
+                    automation_button_interaction,
+                    update_automation_text,
+                    escape_to_game,
+                )
+                    .run_if(in_state(AppState::Automation)),
+            )
+            .add_systems(Update, auto_buy.run_if(in_state(PauseState::Unpaused)))
+            .insert_resource(AutoBuyTimer(Timer::from_seconds(
+                AUTO_BUY_SECS,
+                TimerMode::Repeating,
+            )))
+            .insert_resource(AutoBuyer::load("auto_buyer.ron").unwrap_or_default());
+    }
+}
+
+#[derive(Component)]
+struct CleanupAutomationMenu;
+
+#[derive(Component, Clone, Copy, PartialEq)]
+enum AutomationMenuButton {
+    Enabled,
+    Reserve,
+    Payback,
+    Priority,
+    Back,
+}
+impl AutomationMenuButton {
+    const RULES: [AutomationMenuButton; 4] = [
+        AutomationMenuButton::Enabled,
+        AutomationMenuButton::Reserve,
+        AutomationMenuButton::Payback,
+        AutomationMenuButton::Priority,
+    ];
+
+    fn label(&self, rules: &AutoBuyRules) -> String {
+        use AutomationMenuButton::*;
+
+        match self {
+            Enabled => format!("AUTO-BUY\n{}", if rules.enabled { "ON" } else { "OFF" }),
+            Reserve => format!("KEEP RESERVE\n{}%", rules.reserve_percent),
+            Payback => match rules.max_payback_secs {
+                Some(secs) => format!("PAYBACK\nUNDER {}s", secs),
+                None => "PAYBACK\nANY".to_string(),
+            },
+            Priority => format!("PRIORITY\n{}", rules.priority.label()),
+            Back => "BACK".to_string(),
+        }
+    }
+}
+
+// WHAT EACH TEXT ON THE SCREEN SHOWS, REFRESHED WHEN THE RULES OR LOG CHANGE
+#[derive(Component)]
+enum AutomationText {
+    Status,
+    Rule(AutomationMenuButton),
+    Log,
+}
+
+#[derive(Resource)]
+struct AutoBuyTimer(Timer);
+
+/// One purchase the auto-buyer made.
+#[derive(Deserialize, Serialize)]
+pub struct AutoBuyEntry {
+    title: String,
+    price: BigNumber,
+    // TIME PLAYED WHEN IT WAS BOUGHT
+    at_secs: f64,
+}
+
+/// The player's auto-buyer rules and what they bought, newest first.
+#[derive(Default, Deserialize, Resource, Serialize)]
+#[serde(default)]
+pub struct AutoBuyer {
+    rules: AutoBuyRules,
+    log: VecDeque<AutoBuyEntry>,
+}
+impl Saveable for AutoBuyer {
+    fn save(&self, filename: &str) -> Result<()> {
+        format_save(self, filename)
+    }
+
+    fn load(filename: &str) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        format_load(filename)
+    }
+}
+
+// ONLY RUNS ONCE THE AUTO-BUYER UPGRADE IS OWNED, WHICH SURVIVES REBIRTHS
+fn auto_buy(
+    time: Res<Time>,
+    modifiers: Res<UpgradeModifiers>,
+    mut timer: ResMut<AutoBuyTimer>,
+    mut auto_buyer: ResMut<AutoBuyer>,
+    mut economy: ResMut<GameEconomy>,
+    mut statistics: ResMut<Statistics>,
+) {
+    if !modifiers.auto_buyer() || !timer.0.tick(time.delta()).just_finished() {
+        return;
+    }
+    for _ in 0..MAX_BUYS_PER_PASS {
+        let Some(id) = auto_buyer
+            .rules
+            .choose(&economy)
+            .map(|power| power.id().to_string())
+        else {
+            break;
+        };
+        let Ok(price) = economy.buy(&id, 1) else {
+            break;
+        };
+        statistics.record_purchase(price);
+        let title = economy
+            .power(&id)
+            .map_or(id.clone(), |p| p.title().to_string());
+        info!("[MODIFIED] Auto-Buyer Bought: {} For {}", title, price);
+        auto_buyer.log.push_front(AutoBuyEntry {
+            title,
+            price,
+            at_secs: statistics.played_secs(),
+        });
+        auto_buyer.log.truncate(LOG_LEN);
+    }
+}
+
+fn status(modifiers: &UpgradeModifiers, auto_buyer: &AutoBuyer) -> String {
+    if !modifiers.auto_buyer() {
+        "LOCKED: BUY THE AUTO-BUYER UPGRADE".to_string()
+    } else if auto_buyer.rules.enabled {
+        "RUNNING".to_string()
+    } else {
+        "SWITCHED OFF".to_string()
+    }
+}
+
+fn log_text(auto_buyer: &AutoBuyer) -> String {
+    if auto_buyer.log.is_empty() {
+        return "NOTHING BOUGHT YET".to_string();
+    }
+    auto_buyer
+        .log
+        .iter()
+        .map(|entry| {
+            format!(
+                "{}  {}  -{}",
+                clock(entry.at_secs),
+                entry.title,
+                entry.price
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn startup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    modifiers: Res<UpgradeModifiers>,
+    auto_buyer: Res<AutoBuyer>,
+) {
+    let font = asset_server.load("fonts/PublicPixel.ttf");
+
+    let style = (
+        BorderColor(Pallette::Black.srgb()),
+        BorderRadius::all(Val::Percent(10.0)),
+        BackgroundColor(Pallette::Lighter.srgb()),
+    );
+
+    // SPAWN TITLE AND STATUS
+    commands
+        .spawn((
+            UIButtonParentNode::new(100.0, 20.0, 0.0),
+            UIButtonParentNode::marker(),
+            CleanupAutomationMenu,
+        ))
+        .with_child((
+            Text::new(status(&modifiers, &auto_buyer)),
+            TextFont {
+                font: font.clone(),
+                font_size: 30.0,
+                ..default()
+            },
+            TextColor(Pallette::White.srgb()),
+            AutomationText::Status,
+        ));
+
+    // SPAWN RULE BUTTONS
+    commands
+        .spawn((
+            UIButtonParentNode::new(100.0, 20.0, 20.0),
+            UIButtonParentNode::marker(),
+            CleanupAutomationMenu,
+        ))
+        .with_children(|parent| {
+            for rule in AutomationMenuButton::RULES {
+                parent
+                    .spawn((
+                        UIButtonChildNode::node(),
+                        UIButtonChildNode::marker(),
+                        Button,
+                        rule,
+                        UIButton,
+                        style,
+                    ))
+                    .with_child((
+                        Text::new(rule.label(&auto_buyer.rules)),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Pallette::Black.srgb()),
+                        AutomationText::Rule(rule),
+                    ));
+            }
+        });
+
+    // SPAWN PURCHASE LOG
+    commands
+        .spawn((
+            AutomationLogNode::node(),
+            AutomationLogNode::marker(),
+            CleanupAutomationMenu,
+        ))
+        .with_child((
+            Text::new(log_text(&auto_buyer)),
+            TextFont {
+                font: font.clone(),
+                font_size: 12.0,
+                ..default()
+            },
+            TextColor(Pallette::Lighter.srgb()),
+            AutomationText::Log,
+        ));
+
+    // SPAWN BACK BUTTON NODE
+    commands
+        .spawn((
+            UIButtonParentNode::new(100.0, 20.0, 80.0),
+            UIButtonParentNode::marker(),
+            CleanupAutomationMenu,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    UIButtonChildNode::node(),
+                    UIButtonChildNode::marker(),
+                    Button,
+                    AutomationMenuButton::Back,
+                    UIButton,
+                    style,
+                ))
+                .with_child((
+                    Text::new("BACK"),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 33.0,
+                        ..default()
+                    },
+                    TextColor(Pallette::Black.srgb()),
+                ));
+        });
+
+    info!("[SPAWNED] Automation Menu Entities.");
+}
+
+fn cleanup(mut commands: Commands, query_cleanup: Query<Entity, With<CleanupAutomationMenu>>) {
+    for entity in query_cleanup.iter() {
+        commands.entity(entity).despawn_recursive();
+        info!("[DESPAWNED] Automation Menu Entities");
+    }
+}
+
+// EACH RULE BUTTON STEPS TO THE NEXT SETTING, WRAPPING AROUND
+fn automation_button_interaction(
+    mut auto_buyer: ResMut<AutoBuyer>,
+    mut evw_save: EventWriter<Save>,
+    mut next_state: ResMut<NextState<AppState>>,
+    interaction_query: Query<(&Interaction, &AutomationMenuButton), Changed<Interaction>>,
+) {
+    use AutomationMenuButton::*;
+
+    for (interaction, amb) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let rules = &mut auto_buyer.rules;
+        match amb {
+            Enabled => rules.enabled = !rules.enabled,
+            Reserve => {
+                let next = RESERVE_STEPS
+                    .iter()
+                    .position(|step| *step == rules.reserve_percent)
+                    .map_or(0, |i| (i + 1) % RESERVE_STEPS.len());
+                rules.reserve_percent = RESERVE_STEPS[next];
+            }
+            Payback => {
+                let next = PAYBACK_STEPS
+                    .iter()
+                    .position(|step| *step == rules.max_payback_secs)
+                    .map_or(0, |i| (i + 1) % PAYBACK_STEPS.len());
+                rules.max_payback_secs = PAYBACK_STEPS[next];
+            }
+            Priority => rules.priority = rules.priority.next(),
+            Back => {
+                next_state.set(AppState::Playing);
+                info!("[MODIFIED] AppState >> Playing");
+                continue;
+            }
+        }
+        evw_save.send(Save);
+        info!(
+            "[MODIFIED] Auto-Buyer Rule: {}",
+            amb.label(rules).replace('\n', " ")
+        );
+    }
+}
+
+fn update_automation_text(
+    modifiers: Res<UpgradeModifiers>,
+    auto_buyer: Res<AutoBuyer>,
+    mut query_text: Query<(&AutomationText, &mut Text)>,
+) {
+    if auto_buyer.is_changed() {
+        for (automation_text, mut text) in &mut query_text {
+            **text = match automation_text {
+                AutomationText::Status => status(&modifiers, &auto_buyer),
+                AutomationText::Rule(rule) => rule.label(&auto_buyer.rules),
+                AutomationText::Log => log_text(&auto_buyer),
+            };
+        }
+    }
+}
+
+fn escape_to_game(keys: Res<ButtonInput<KeyCode>>, mut next_state: ResMut<NextState<AppState>>) {
+    if keys.just_pressed(KeyCode::Escape) {
+        next_state.set(AppState::Playing);
+        info!("[MODIFIED] AppState >> Playing");
+    }
+}
@@ -0,0 +1,303 @@
+//! Headless balance simulator.
+//!
+//! Runs the game economy without rendering anything, buying powers by one or
+//! more strategies, and writes unlock times, production curves and dead zones
+//! for each as CSV or JSON. Synergies apply as in the game, but it plays a
+//! first run that buys no upgrades, so there is no upgrade or prestige bonus.
+//!
+//! ```text
+//! cargo run --bin ftp-sim -- --strategy best-payback --hours 4 --format json
+//! ```
+
+mod report;
+mod strategy;
+
+use bevy::{scene::ron::de::from_str, utils::hashbrown};
+use flight_the_power::{
+    economy::{Economy, PowerDefinition},
+    number::BigNumber,
+    synergy::Synergies,
+};
+use serde::Deserialize;
+use std::{collections::HashMap, env, fs, path::PathBuf, process::ExitCode};
+
+use report::{DeadZone, Report, Sample, Unlock, MODIFIERS};
+use strategy::{Strategy, STRATEGIES};
+
+const USAGE: &str = "\
+USAGE: ftp-sim [OPTIONS]
+
+OPTIONS:
+    --definitions <PATH>    POWER DEFINITIONS [assets/data/definitions.powers.ron]
+    --synergies <PATH>      SYNERGY RULES [assets/data/synergies.ron]
+    --strategy <NAME>       greedy-cheapest | best-payback | newest | all [all]
+    --hours <HOURS>         SIMULATED PLAYTIME [4]
+    --step <SECS>           SIMULATION STEP [0.1]
+    --sample <SECS>         PRODUCTION CURVE INTERVAL [60]
+    --clicks <PER SEC>      MANUAL CLICKS PER SECOND [5]
+    --dead-zone <SECS>      SHORTEST GAP REPORTED AS A DEAD ZONE [300]
+    --format <FORMAT>       csv | json [csv]
+    --out <DIR>             REPORT DIRECTORY [sim]";
+
+#[derive(Deserialize)]
+struct PowerDefinitions(Vec<PowerDefinition>);
+
+#[derive(Clone, Copy, PartialEq)]
+enum Format {
+    Csv,
+    Json,
+}
+
+struct Options {
+    definitions: PathBuf,
+    synergies: PathBuf,
+    strategies: Vec<String>,
+    duration_secs: f64,
+    step_secs: f64,
+    sample_secs: f64,
+    clicks_per_sec: f64,
+    dead_zone_secs: f64,
+    format: Format,
+    out: PathBuf,
+}
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            definitions: PathBuf::from("assets/data/definitions.powers.ron"),
+            synergies: PathBuf::from("assets/data/synergies.ron"),
+            strategies: STRATEGIES.iter().map(|name| name.to_string()).collect(),
+            duration_secs: 4.0 * 3600.0,
+            step_secs: 0.1,
+            sample_secs: 60.0,
+            clicks_per_sec: 5.0,
+            dead_zone_secs: 300.0,
+            format: Format::Csv,
+            out: PathBuf::from("sim"),
+        }
+    }
+}
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut options = Self::default();
+        while let Some(flag) = args.next() {
+            if flag == "--help" || flag == "-h" {
+                return Err(USAGE.to_string());
+            }
+            let value = args
+                .next()
+                .ok_or_else(|| format!("missing value for {flag}"))?;
+            let number = || {
+                value
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|n| *n >= 0.0)
+                    .ok_or_else(|| format!("invalid value for {flag}: {value}"))
+            };
+            match flag.as_str() {
+                "--definitions" => options.definitions = PathBuf::from(&value),
+                "--synergies" => options.synergies = PathBuf::from(&value),
+                "--strategy" if value == "all" => {}
+                "--strategy" => {
+                    if strategy::from_name(&value).is_none() {
+                        return Err(format!("unknown strategy: {value}"));
+                    }
+                    options.strategies = vec![value.clone()];
+                }
+                "--hours" => options.duration_secs = number()? * 3600.0,
+                "--step" => options.step_secs = number()?,
+                "--sample" => options.sample_secs = number()?,
+                "--clicks" => options.clicks_per_sec = number()?,
+                "--dead-zone" => options.dead_zone_secs = number()?,
+                "--format" => {
+                    options.format = match value.as_str() {
+                        "csv" => Format::Csv,
+                        "json" => Format::Json,
+                        _ => return Err(format!("unknown format: {value}")),
+                    }
+                }
+                "--out" => options.out = PathBuf::from(&value),
+                _ => return Err(format!("unknown option: {flag}\n\n{USAGE}")),
+            }
+        }
+        if options.step_secs <= 0.0 {
+            return Err("--step must be above zero".to_string());
+        }
+        Ok(options)
+    }
+}
+
+fn main() -> ExitCode {
+    let options = match Options::parse(env::args().skip(1)) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{e}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let definitions = match fs::read_to_string(&options.definitions)
+        .map_err(|e| e.to_string())
+        .and_then(|s| from_str::<PowerDefinitions>(&s).map_err(|e| e.to_string()))
+    {
+        Ok(definitions) => definitions.0,
+        Err(e) => {
+            eprintln!(
+                "[ERROR] Power Definitions Failed To Load: {}: {}",
+                options.definitions.display(),
+                e
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+    println!("[LOADED] Power Definitions: {}", definitions.len());
+
+    let synergies = match fs::read_to_string(&options.synergies) {
+        Ok(s) => Synergies::from_ron(&s),
+        Err(e) => {
+            eprintln!(
+                "[ERROR] Synergies Failed To Load: {}: {}",
+                options.synergies.display(),
+                e
+            );
+            return ExitCode::FAILURE;
+        }
+    };
+
+    if let Err(e) = fs::create_dir_all(&options.out) {
+        eprintln!("[ERROR] {}: {}", options.out.display(), e);
+        return ExitCode::FAILURE;
+    }
+
+    for name in options.strategies.iter() {
+        let Some(mut strategy) = strategy::from_name(name) else {
+            continue;
+        };
+        let report = simulate(&definitions, &synergies, strategy.as_mut(), &options);
+        report.print_summary();
+        let written = match options.format {
+            Format::Csv => report.write_csv(&options.out),
+            Format::Json => report.write_json(&options.out),
+        };
+        if let Err(e) = written {
+            eprintln!("[ERROR] Report Failed To Write: {e}");
+            return ExitCode::FAILURE;
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+fn simulate(
+    definitions: &[PowerDefinition],
+    synergies: &Synergies,
+    strategy: &mut dyn Strategy,
+    options: &Options,
+) -> Report {
+    let mut economy = Economy::new(definitions);
+    apply_synergies(&mut economy, synergies);
+    let mut unlocked_at: HashMap<String, f64> = HashMap::new();
+    let mut first_bought_at: HashMap<String, f64> = HashMap::new();
+    let mut production = Vec::new();
+    let mut dead_zones = Vec::new();
+
+    // COUNT STEPS RATHER THAN SUMMING THEM SO TIMES DO NOT DRIFT
+    let mut steps: u64 = 0;
+    let mut time = 0.0;
+    let mut produced = 0.0;
+    let mut clicks_owed = 0.0;
+    let mut next_sample = 0.0;
+    let mut purchases = 0;
+    let mut last_progress = 0.0;
+    let mut waiting_for = None;
+
+    // A GAP BETWEEN PURCHASES AND UNLOCKS THAT RAN TOO LONG
+    let mut progress = |now: f64, last: &mut f64, waiting_for: &Option<String>| {
+        if now - *last >= options.dead_zone_secs {
+            dead_zones.push(DeadZone {
+                start_secs: *last,
+                end_secs: now,
+                waiting_for: waiting_for.clone(),
+            });
+        }
+        *last = now;
+    };
+
+    while time < options.duration_secs {
+        if time >= next_sample {
+            production.push(Sample {
+                time_secs: time,
+                total_power: economy.total_power().to_f64(),
+                produced,
+                power_per_second: economy.power_per_second().to_f64(),
+            });
+            next_sample += options.sample_secs.max(options.step_secs);
+        }
+
+        clicks_owed += options.clicks_per_sec * options.step_secs;
+        while clicks_owed >= 1.0 {
+            produced += economy.click().to_f64();
+            clicks_owed -= 1.0;
+        }
+
+        // ONE RUN, SO EVERYTHING EARNED IS ALSO EVERYTHING EVER EARNED
+        let inputs = economy.unlock_inputs_mut();
+        inputs.earned = BigNumber::from(produced);
+        inputs.lifetime_earned = inputs.earned;
+
+        let step = economy.step(options.step_secs);
+        steps += 1;
+        time = steps as f64 * options.step_secs;
+        produced += step.earned().to_f64();
+        for id in step.unlocked {
+            unlocked_at.insert(id, time);
+            progress(time, &mut last_progress, &waiting_for);
+        }
+
+        // BUY UNTIL THE STRATEGY IS SAVING FOR SOMETHING IT CANNOT AFFORD YET
+        while let Some(id) = strategy.choose(&economy) {
+            if economy.buy(&id, 1).is_err() {
+                waiting_for = Some(id);
+                break;
+            }
+            purchases += 1;
+            apply_synergies(&mut economy, synergies);
+            first_bought_at.entry(id).or_insert(time);
+            progress(time, &mut last_progress, &waiting_for);
+        }
+    }
+    progress(time, &mut last_progress, &waiting_for);
+
+    let unlocks = economy
+        .powers()
+        .iter()
+        .map(|power| Unlock {
+            id: power.id().to_string(),
+            title: power.title().to_string(),
+            unlocked_at_secs: unlocked_at.get(power.id()).copied(),
+            first_bought_at_secs: first_bought_at.get(power.id()).copied(),
+            owned: power.owned(),
+        })
+        .collect();
+
+    Report {
+        strategy: strategy.name().to_string(),
+        modifiers: MODIFIERS,
+        duration_secs: time,
+        purchases,
+        unlocks,
+        production,
+        dead_zones,
+    }
+}
+
+// SYNERGIES ONLY CHANGE WITH WHAT IS OWNED, SO EVERY PURCHASE REAPPLIES THEM
+fn apply_synergies(economy: &mut Economy, synergies: &Synergies) {
+    let owned: hashbrown::HashMap<String, i64> = economy
+        .powers()
+        .iter()
+        .map(|power| (power.id().to_string(), power.owned()))
+        .collect();
+    for id in owned.keys() {
+        let multiplier = synergies.multiplier(id, &owned);
+        economy.set_modifiers(id, BigNumber::from(multiplier), 1.0);
+    }
+}
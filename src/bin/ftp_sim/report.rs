@@ -0,0 +1,116 @@
+use flight_the_power::number::clock;
+use serde::Serialize;
+use std::{fs, io::Result, path::Path};
+
+/// When a power first unlocked and was first bought, in simulated seconds.
+#[derive(Serialize)]
+pub struct Unlock {
+    pub id: String,
+    pub title: String,
+    pub unlocked_at_secs: Option<f64>,
+    pub first_bought_at_secs: Option<f64>,
+    pub owned: i64,
+}
+
+/// One point on the production curve.
+#[derive(Serialize)]
+pub struct Sample {
+    pub time_secs: f64,
+    pub total_power: f64,
+    // EVERYTHING EVER PRODUCED OR CLICKED, SPENDING NEVER LOWERS IT
+    pub produced: f64,
+    pub power_per_second: f64,
+}
+
+/// A stretch with no purchase and no unlock, long enough to feel stuck.
+#[derive(Serialize)]
+pub struct DeadZone {
+    pub start_secs: f64,
+    pub end_secs: f64,
+    pub waiting_for: Option<String>,
+}
+
+// THE NUMBERS ARE ONLY AS GOOD AS WHAT THE SIMULATOR MODELS, SO EVERY REPORT SAYS SO
+pub const MODIFIERS: &str = "base economy with synergies, no upgrades or prestige bonus";
+
+#[derive(Serialize)]
+pub struct Report {
+    pub strategy: String,
+    pub modifiers: &'static str,
+    pub duration_secs: f64,
+    pub purchases: u64,
+    pub unlocks: Vec<Unlock>,
+    pub production: Vec<Sample>,
+    pub dead_zones: Vec<DeadZone>,
+}
+impl Report {
+    pub fn write_json(&self, dir: &Path) -> Result<()> {
+        let path = dir.join(format!("{}.json", self.strategy));
+        fs::write(&path, serde_json::to_string_pretty(self)?)?;
+        println!("[SAVED] {}", path.display());
+        Ok(())
+    }
+
+    pub fn write_csv(&self, dir: &Path) -> Result<()> {
+        let mut unlocks = String::from("id,title,unlocked_at_secs,first_bought_at_secs,owned\n");
+        for unlock in self.unlocks.iter() {
+            unlocks += &format!(
+                "{},\"{}\",{},{},{}\n",
+                unlock.id,
+                unlock.title.replace('"', "\"\""),
+                optional(unlock.unlocked_at_secs),
+                optional(unlock.first_bought_at_secs),
+                unlock.owned
+            );
+        }
+
+        let mut production = String::from("time_secs,total_power,produced,power_per_second\n");
+        for sample in self.production.iter() {
+            production += &format!(
+                "{:.3},{:e},{:e},{:e}\n",
+                sample.time_secs, sample.total_power, sample.produced, sample.power_per_second
+            );
+        }
+
+        let mut dead_zones = String::from("start_secs,end_secs,length_secs,waiting_for\n");
+        for zone in self.dead_zones.iter() {
+            dead_zones += &format!(
+                "{:.3},{:.3},{:.3},{}\n",
+                zone.start_secs,
+                zone.end_secs,
+                zone.end_secs - zone.start_secs,
+                zone.waiting_for.as_deref().unwrap_or_default()
+            );
+        }
+
+        for (name, contents) in [
+            ("unlocks", unlocks),
+            ("production", production),
+            ("dead_zones", dead_zones),
+        ] {
+            let path = dir.join(format!("{}.{}.csv", self.strategy, name));
+            fs::write(&path, format!("# {}\n{}", self.modifiers, contents))?;
+            println!("[SAVED] {}", path.display());
+        }
+        Ok(())
+    }
+
+    pub fn print_summary(&self) {
+        println!(
+            "[{}] {} Purchases, {} Dead Zones",
+            self.strategy,
+            self.purchases,
+            self.dead_zones.len()
+        );
+        for unlock in self.unlocks.iter() {
+            match unlock.unlocked_at_secs {
+                Some(secs) => println!("    {:<32} UNLOCKED AT {}", unlock.title, clock(secs)),
+                None => println!("    {:<32} NEVER UNLOCKED", unlock.title),
+            }
+        }
+    }
+}
+
+fn optional(secs: Option<f64>) -> String {
+    secs.map(|secs| format!("{secs:.3}")).unwrap_or_default()
+}
@@ -0,0 +1,79 @@
+use flight_the_power::{
+    auto_buy::{payback_secs, AutoBuyPriority},
+    economy::{Economy, Power},
+};
+
+/// Decides what the simulated player saves up for next.
+pub trait Strategy {
+    fn name(&self) -> &'static str;
+
+    /// The power to buy one copy of next, or `None` to buy nothing.
+    /// The simulator buys it as soon as it is affordable and asks again.
+    fn choose(&mut self, economy: &Economy) -> Option<String>;
+}
+
+pub const STRATEGIES: [&str; 3] = ["greedy-cheapest", "best-payback", "newest"];
+
+pub fn from_name(name: &str) -> Option<Box<dyn Strategy>> {
+    match name {
+        "greedy-cheapest" => Some(Box::new(GreedyCheapest)),
+        "best-payback" => Some(Box::new(BestPayback)),
+        "newest" => Some(Box::new(Newest)),
+        _ => None,
+    }
+}
+
+// ONLY POWERS THAT ARE UNLOCKED, NOT MAXED AND ACTUALLY PRODUCE ARE WORTH BUYING
+fn candidates(economy: &Economy) -> impl Iterator<Item = &Power> {
+    economy.unlocked().filter(|power| {
+        power.remaining() > 0
+            && power.period() > 0.0
+            && !power.definition().production_amount.is_zero()
+    })
+}
+
+/// Always buys whatever is cheapest right now.
+pub struct GreedyCheapest;
+impl Strategy for GreedyCheapest {
+    fn name(&self) -> &'static str {
+        "greedy-cheapest"
+    }
+
+    fn choose(&mut self, economy: &Economy) -> Option<String> {
+        AutoBuyPriority::Cheapest
+            .pick(candidates(economy))
+            .map(|power| power.id().to_string())
+    }
+}
+
+/// Buys whatever pays for itself soonest, counting the wait to afford it.
+pub struct BestPayback;
+impl Strategy for BestPayback {
+    fn name(&self) -> &'static str {
+        "best-payback"
+    }
+
+    fn choose(&mut self, economy: &Economy) -> Option<String> {
+        candidates(economy)
+            .map(|power| {
+                let wait = economy.time_to_afford(power.id(), 1).unwrap_or(f64::MAX);
+                (power, wait + payback_secs(power))
+            })
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(power, _)| power.id().to_string())
+    }
+}
+
+/// Chases the furthest power along the list, like a player eager to see what it does.
+pub struct Newest;
+impl Strategy for Newest {
+    fn name(&self) -> &'static str {
+        "newest"
+    }
+
+    fn choose(&mut self, economy: &Economy) -> Option<String> {
+        AutoBuyPriority::Newest
+            .pick(candidates(economy))
+            .map(|power| power.id().to_string())
+    }
+}
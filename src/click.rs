@@ -0,0 +1,326 @@
+use bevy::{prelude::*, scene::ron::de::from_str};
+use rand::prelude::*;
+use serde::Deserialize;
+
+use crate::{
+    game::PowerPerSecond,
+    number::BigNumber,
+    prestige::Prestige,
+    ui::{ClickTextNode, ComboBarNode, ComboNode, Pallette},
+    upgrades::UpgradeModifiers,
+    AppState, GameRng, PauseState,
+};
+
+// WHAT A CLICK IS WORTH BEFORE ANYTHING BOOSTS IT
+const BASE_CLICK: f64 = 1.0;
+// HOW LONG "CRIT!" STAYS ON THE COMBO TEXT
+const CRIT_FLASH_SECS: f32 = 0.6;
+
+pub struct ClickPlugin;
+impl Plugin for ClickPlugin {
+    fn name(&self) -> &str {
+        "Click Plugin"
+    }
+
+    fn build(&self, app: &mut App) {
+        app.add_event::<AddClickBuff>()
+            .init_resource::<ClickValue>()
+            .insert_resource(Combo::from_ron(include_str!("../assets/data/combo.ron")))
+            .add_systems(OnEnter(AppState::Playing), startup)
+            .add_systems(OnExit(AppState::Playing), cleanup)
+            .add_systems(Update, evr_add_click_buff)
+            .add_systems(
+                Update,
+                (
+                    (tick_click_buffs, update_click_value, update_click_text).chain(),
+                    (decay_combo, update_combo_hud).chain(),
+                )
+                    .run_if(in_state(PauseState::Unpaused)),
+            );
+    }
+}
+
+#[derive(Component)]
+struct CleanupClick;
+
+#[derive(Component)]
+struct ClickText;
+
+#[derive(Component)]
+struct ComboText;
+
+#[derive(Component)]
+struct ComboFill;
+
+/// Temporarily multiplies click value for `secs` seconds of unpaused play.
+#[derive(Event)]
+pub struct AddClickBuff {
+    pub multiplier: f64,
+    pub secs: f32,
+}
+
+struct ClickBuff {
+    multiplier: f64,
+    timer: Timer,
+}
+
+/// Power earned by a single click, shared by manual and auto clicks.
+#[derive(Default, Resource)]
+pub struct ClickValue {
+    value: BigNumber,
+    buffs: Vec<ClickBuff>,
+}
+impl ClickValue {
+    pub fn value(&self) -> BigNumber {
+        self.value
+    }
+
+    fn buff_multiplier(&self) -> f64 {
+        self.buffs.iter().map(|buff| buff.multiplier).product()
+    }
+
+    // LONGEST RUNNING BUFF, FOR THE HUD
+    fn buff_secs_left(&self) -> f32 {
+        self.buffs
+            .iter()
+            .map(|buff| buff.timer.remaining_secs())
+            .fold(0.0, f32::max)
+    }
+}
+
+#[derive(Deserialize)]
+struct ComboTier {
+    meter: f32,
+    multiplier: f64,
+}
+
+/// Tuning from `assets/data/combo.ron`.
+#[derive(Default, Deserialize)]
+struct ComboConfig {
+    gain_per_click: f32,
+    min_click_interval_secs: f32,
+    decay_delay_secs: f32,
+    decay_per_sec: f32,
+    tiers: Vec<ComboTier>,
+    crit_chance: f64,
+    crit_multiplier: f64,
+}
+
+/// Streak meter built by manual clicks. Only `screen_click` feeds it, so
+/// auto clicks can never build combo or roll crits.
+#[derive(Resource)]
+pub struct Combo {
+    config: ComboConfig,
+    meter: f32,
+    since_click: f32,
+    crit_flash: f32,
+}
+impl Combo {
+    fn from_ron(s: &str) -> Self {
+        let config = match from_str::<ComboConfig>(s) {
+            Ok(mut config) => {
+                // gen_bool PANICS ON NAN, SO A BAD CHANCE MEANS NO CRITS
+                if !config.crit_chance.is_finite() {
+                    info!("[INVALID] Combo Crit Chance: {}", config.crit_chance);
+                    config.crit_chance = 0.0;
+                }
+                config.crit_chance = config.crit_chance.clamp(0.0, 1.0);
+                info!("[LOADED] Combo Tiers: {}", config.tiers.len());
+                config
+            }
+            Err(e) => {
+                info!("[ERROR] Combo Failed To Parse: {}", e);
+                ComboConfig::default()
+            }
+        };
+        Self {
+            config,
+            meter: 0.0,
+            since_click: f32::MAX,
+            crit_flash: 0.0,
+        }
+    }
+
+    fn multiplier(&self) -> f64 {
+        self.config
+            .tiers
+            .iter()
+            // SMALL SLACK SO FLOAT DRIFT CAN STILL REACH A FULL METER
+            .filter(|tier| self.meter + 1e-4 >= tier.meter)
+            .map(|tier| tier.multiplier)
+            .fold(1.0, f64::max)
+    }
+
+    /// Pays out a manual click: builds the meter, applies the combo tier and
+    /// rolls for a crit. Returns the payout and whether it crit.
+    pub fn click(&mut self, value: BigNumber, rng: &mut GameRng) -> (BigNumber, bool) {
+        // CLICKS FASTER THAN A HUMAN CAN MANAGE STILL PAY, BUT DO NOT BUILD
+        if self.since_click >= self.config.min_click_interval_secs {
+            self.meter = (self.meter + self.config.gain_per_click).min(1.0);
+        }
+        self.since_click = 0.0;
+
+        let payout = value * self.multiplier();
+        if rng.gen_bool(self.config.crit_chance) {
+            self.crit_flash = CRIT_FLASH_SECS;
+            (payout * self.config.crit_multiplier, true)
+        } else {
+            (payout, false)
+        }
+    }
+}
+
+fn startup(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let font = asset_server.load("fonts/PublicPixel.ttf");
+
+    commands.spawn((
+        ClickTextNode::node(),
+        ClickTextNode::marker(),
+        Text::new(""),
+        TextFont {
+            font: font.clone(),
+            font_size: 20.0,
+            ..default()
+        },
+        TextColor(Pallette::Lighter.srgb()),
+        ClickText,
+        CleanupClick,
+    ));
+
+    // SPAWN COMBO METER
+    commands
+        .spawn((ComboNode::node(), ComboNode::marker(), CleanupClick))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    ComboBarNode::node(),
+                    ComboBarNode::marker(),
+                    BorderColor(Pallette::Black.srgb()),
+                    BackgroundColor(Pallette::Darker.srgb()),
+                ))
+                .with_child((
+                    Node {
+                        width: Val::Percent(0.0),
+                        height: Val::Percent(100.0),
+                        ..default()
+                    },
+                    BackgroundColor(Pallette::Lighter.srgb()),
+                    ComboFill,
+                ));
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font,
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Pallette::Lighter.srgb()),
+                ComboText,
+            ));
+        });
+    info!("[SPAWNED] Click Text + Combo Meter");
+}
+
+fn cleanup(mut commands: Commands, query_entity: Query<Entity, With<CleanupClick>>) {
+    for entity in query_entity.iter() {
+        commands.entity(entity).despawn_recursive();
+        info!("[DESPAWNED] Click Text + Combo Meter");
+    }
+}
+
+fn evr_add_click_buff(
+    mut evr_add_click_buff: EventReader<AddClickBuff>,
+    mut click_value: ResMut<ClickValue>,
+) {
+    for ev in evr_add_click_buff.read() {
+        click_value.buffs.push(ClickBuff {
+            multiplier: ev.multiplier,
+            timer: Timer::from_seconds(ev.secs, TimerMode::Once),
+        });
+        info!("[MODIFIED] Click Buff: x{} For {}s", ev.multiplier, ev.secs);
+    }
+}
+
+fn tick_click_buffs(time: Res<Time>, mut click_value: ResMut<ClickValue>) {
+    if click_value.buffs.is_empty() {
+        return;
+    }
+    for buff in click_value.buffs.iter_mut() {
+        buff.timer.tick(time.delta());
+    }
+    click_value.buffs.retain(|buff| !buff.timer.finished());
+}
+
+fn update_click_value(
+    prestige: Res<Prestige>,
+    modifiers: Res<UpgradeModifiers>,
+    power_per_second: Res<PowerPerSecond>,
+    mut click_value: ResMut<ClickValue>,
+) {
+    let value = (BigNumber::new(BASE_CLICK) + **power_per_second * modifiers.click_per_second())
+        * modifiers.click()
+        * prestige.click_multiplier()
+        * click_value.buff_multiplier();
+
+    // ONLY FLAG A CHANGE WHEN THE VALUE ACTUALLY MOVES
+    if click_value.value != value {
+        click_value.value = value;
+    }
+}
+
+fn update_click_text(
+    click_value: Res<ClickValue>,
+    query_added: Query<(), Added<ClickText>>,
+    mut query_click_text: Query<&mut Text, With<ClickText>>,
+) {
+    if !click_value.is_changed() && query_added.is_empty() {
+        return;
+    }
+    let label = if click_value.buffs.is_empty() {
+        format!("CLICK: +{}", click_value.value())
+    } else {
+        format!(
+            "CLICK: +{} (x{} {:.0}s)",
+            click_value.value(),
+            click_value.buff_multiplier(),
+            click_value.buff_secs_left().ceil()
+        )
+    };
+    for mut text in &mut query_click_text {
+        **text = label.clone();
+    }
+}
+
+fn decay_combo(time: Res<Time>, mut combo: ResMut<Combo>) {
+    let delta = time.delta_secs();
+    combo.since_click += delta;
+    combo.crit_flash = (combo.crit_flash - delta).max(0.0);
+    if combo.since_click > combo.config.decay_delay_secs {
+        combo.meter = (combo.meter - combo.config.decay_per_sec * delta).max(0.0);
+    }
+}
+
+fn update_combo_hud(
+    combo: Res<Combo>,
+    mut query_fill: Query<&mut Node, With<ComboFill>>,
+    mut query_combo_text: Query<&mut Text, With<ComboText>>,
+) {
+    for mut node in &mut query_fill {
+        node.width = Val::Percent(combo.meter * 100.0);
+    }
+
+    let label = if combo.crit_flash > 0.0 {
+        format!("CRIT! x{}", combo.config.crit_multiplier)
+    } else {
+        format!(
+            "COMBO x{} | CRIT {}%",
+            combo.multiplier(),
+            combo.config.crit_chance * 100.0
+        )
+    };
+    for mut text in &mut query_combo_text {
+        if **text != label {
+            **text = label.clone();
+        }
+    }
+}
@@ -0,0 +1,1157 @@
+//! The idle economy on its own, with no Bevy in sight.
+//!
+//! An [`Economy`] owns the power definitions, what the player has of each, and
+//! the running total. The game plugin drives it once per frame and mirrors the
+//! result onto entities; tools can drive it directly with [`Economy::step`].
+
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt};
+
+use crate::{
+    number::BigNumber,
+    unlock::{UnlockCondition, UnlockContext, UnlockInputs},
+};
+
+// MOST ROUNDS A POWER WITH TRAITS RUNS PER STEP, LONGER STEPS BATCH THEIR PERIODS
+const MAX_TRAIT_BATCHES: u64 = 1000;
+
+/// How the price of a power grows with every copy already owned.
+#[derive(Clone, Default, Deserialize, Serialize)]
+pub enum CostScaling {
+    // EVERY COPY COSTS THE BASE COST
+    #[default]
+    Flat,
+    // BASE * RATE^OWNED
+    Exponential(f64),
+    // BASE + STEP * OWNED
+    Linear(BigNumber),
+    // BASE * MULTIPLIER OF THE LAST (OWNED, MULTIPLIER) STEP REACHED
+    Steps(Vec<(i64, f64)>),
+}
+impl CostScaling {
+    pub fn price(&self, base: BigNumber, owned: i64) -> BigNumber {
+        self.price_at(base, owned.max(0))
+    }
+
+    /// Total price of buying `quantity` more copies on top of those already owned.
+    pub fn total_price(&self, base: BigNumber, owned: i64, quantity: i64) -> BigNumber {
+        use CostScaling::*;
+
+        let owned = owned.max(0);
+        if quantity <= 0 {
+            return BigNumber::ZERO;
+        }
+        let qty = BigNumber::from(quantity);
+        match self {
+            Flat => base * qty,
+            Exponential(rate) => {
+                // GEOMETRIC SERIES: FIRST PRICE * (RATE^QTY - 1) / (RATE - 1)
+                let growth = BigNumber::powf(*rate, quantity as f64);
+                let series = if *rate > 1.0 {
+                    (growth - BigNumber::ONE) / (rate - 1.0)
+                } else if *rate < 1.0 {
+                    (BigNumber::ONE - growth) / (1.0 - rate)
+                } else {
+                    qty
+                };
+                self.price_at(base, owned) * series
+            }
+            Linear(step) => {
+                // ARITHMETIC SERIES: QTY * BASE + STEP * (QTY * OWNED + QTY * (QTY - 1) / 2)
+                let steps =
+                    qty * BigNumber::from(owned) + qty * BigNumber::from(quantity - 1) / 2.0;
+                base * qty + *step * steps
+            }
+            Steps(steps) => {
+                // PRICE IS CONSTANT BETWEEN STEPS, SO SUM EACH STRETCH AT ONCE
+                let mut total = BigNumber::ZERO;
+                let mut bought = 0;
+                while bought < quantity {
+                    let at = owned.saturating_add(bought);
+                    let next = steps
+                        .iter()
+                        .map(|(from, _)| *from)
+                        .filter(|from| *from > at)
+                        .min()
+                        .unwrap_or(i64::MAX);
+                    let run = (next - at).min(quantity - bought);
+                    total += self.price_at(base, at) * BigNumber::from(run);
+                    bought += run;
+                }
+                total
+            }
+        }
+    }
+
+    /// Most copies, up to `limit`, whose total price fits within `budget`.
+    pub fn max_affordable(
+        &self,
+        base: BigNumber,
+        owned: i64,
+        budget: BigNumber,
+        limit: i64,
+    ) -> i64 {
+        // TOTAL PRICE ONLY EVER GROWS WITH QUANTITY, SO BINARY SEARCH IT
+        let (mut low, mut high) = (0, limit.max(0));
+        while low < high {
+            let mid = low + (high - low + 1) / 2;
+            if self.total_price(base, owned, mid) <= budget {
+                low = mid;
+            } else {
+                high = mid - 1;
+            }
+        }
+        low
+    }
+
+    fn price_at(&self, base: BigNumber, owned: i64) -> BigNumber {
+        use CostScaling::*;
+
+        match self {
+            Flat => base,
+            Exponential(rate) => base * BigNumber::powf(*rate, owned as f64),
+            Linear(step) => base + *step * BigNumber::from(owned),
+            Steps(steps) => {
+                let multiplier = steps
+                    .iter()
+                    .filter(|(from, _)| *from <= owned)
+                    .max_by_key(|(from, _)| *from)
+                    .map_or(1.0, |(_, multiplier)| *multiplier);
+                base * multiplier
+            }
+        }
+    }
+}
+
+/// A permanent production bonus for owning enough copies of a power.
+#[derive(Clone, Deserialize, Serialize)]
+pub struct Milestone {
+    pub owned: i64,
+    pub multiplier: f64,
+}
+
+/// An optional quirk of how a power runs, checked every production period.
+#[derive(Clone, Deserialize, Serialize)]
+pub enum PowerTrait {
+    // EVERY PERIOD COSTS THIS MUCH PER COPY, NOTHING IS MADE WHEN THE BANK CANNOT PAY
+    Upkeep(BigNumber),
+    // EFFICIENCY DROPS EVERY PERIOD DOWN TO THE FLOOR, EACH MAINTENANCE CLICK RESTORES `repair`
+    Decay {
+        per_period: f64,
+        floor: f64,
+        repair: f64,
+    },
+    // EACH PERIOD MIGHT FAIL, PAYING NOTHING AND FINING `penalty` TIMES THE PAYOUT
+    Risk {
+        chance: f64,
+        penalty: f64,
+    },
+}
+
+/// Design data for one power. Ships with the game in `*.powers.ron` and is
+/// never saved, so rebalances reach every existing save.
+#[derive(Clone, Deserialize)]
+pub struct PowerDefinition {
+    pub title: String,
+    pub id: String,
+    // THE NUMBER SAVES USED FOR THIS POWER BEFORE STRING IDS
+    #[serde(default)]
+    pub legacy_id: Option<usize>,
+    pub sprite_index: usize,
+    pub order: u32,
+    pub cost: BigNumber,
+    pub production_amount: BigNumber,
+    // SECONDS BETWEEN PAYOUTS
+    pub production_rate: f64,
+    pub max_owned: BigNumber,
+    pub unlock: UnlockCondition,
+    #[serde(default)]
+    pub cost_scaling: CostScaling,
+    #[serde(default)]
+    pub milestones: Vec<Milestone>,
+    #[serde(default)]
+    pub traits: Vec<PowerTrait>,
+}
+
+/// One power as the economy runs it: its definition plus everything that changes.
+#[derive(Clone)]
+pub struct Power {
+    definition: PowerDefinition,
+    owned: i64,
+    unlocked: bool,
+    // SECONDS INTO THE CURRENT PRODUCTION PERIOD
+    elapsed: f64,
+    // SET FROM OUTSIDE, E.G. UPGRADES, SYNERGIES, PRESTIGE AND EVENTS
+    production_multiplier: BigNumber,
+    rate_multiplier: f64,
+    halted: bool,
+    // ONLY EVER BELOW 1.0 FOR POWERS THAT DECAY
+    efficiency: f64,
+    // THE LAST PERIOD'S UPKEEP COULD NOT BE PAID
+    out_of_fuel: bool,
+}
+impl Power {
+    fn new(definition: PowerDefinition) -> Self {
+        Self {
+            definition,
+            owned: 0,
+            unlocked: false,
+            elapsed: 0.0,
+            production_multiplier: BigNumber::ONE,
+            rate_multiplier: 1.0,
+            halted: false,
+            efficiency: 1.0,
+            out_of_fuel: false,
+        }
+    }
+
+    pub fn definition(&self) -> &PowerDefinition {
+        &self.definition
+    }
+
+    pub fn id(&self) -> &str {
+        &self.definition.id
+    }
+
+    pub fn title(&self) -> &str {
+        &self.definition.title
+    }
+
+    pub fn owned(&self) -> i64 {
+        self.owned
+    }
+
+    pub fn unlocked(&self) -> bool {
+        self.unlocked
+    }
+
+    pub fn halted(&self) -> bool {
+        self.halted
+    }
+
+    pub fn efficiency(&self) -> f64 {
+        self.efficiency
+    }
+
+    pub fn out_of_fuel(&self) -> bool {
+        self.out_of_fuel
+    }
+
+    pub fn traits(&self) -> &[PowerTrait] {
+        &self.definition.traits
+    }
+
+    /// What one period costs to run, per copy owned.
+    pub fn upkeep(&self) -> BigNumber {
+        self.traits()
+            .iter()
+            .map(|power_trait| match power_trait {
+                PowerTrait::Upkeep(amount) => *amount,
+                _ => BigNumber::ZERO,
+            })
+            .sum()
+    }
+
+    /// True when a maintenance click would restore some efficiency.
+    pub fn needs_maintenance(&self) -> bool {
+        self.efficiency < 1.0
+            && self
+                .traits()
+                .iter()
+                .any(|power_trait| matches!(power_trait, PowerTrait::Decay { .. }))
+    }
+
+    pub fn max_owned(&self) -> i64 {
+        self.definition.max_owned.to_i64()
+    }
+
+    /// Copies that can still be bought before hitting max owned.
+    pub fn remaining(&self) -> i64 {
+        self.max_owned().saturating_sub(self.owned).max(0)
+    }
+
+    /// Seconds between payouts, after rate modifiers.
+    pub fn period(&self) -> f64 {
+        self.definition.production_rate * self.rate_multiplier
+    }
+
+    /// How far through the current period this power is, from 0.0 to 1.0.
+    pub fn progress(&self) -> f64 {
+        let period = self.period();
+        if period > 0.0 {
+            (self.elapsed / period).clamp(0.0, 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    pub fn production_multiplier(&self) -> BigNumber {
+        self.production_multiplier
+    }
+
+    /// What one payout is worth with every copy owned and every modifier applied.
+    pub fn payout(&self) -> BigNumber {
+        self.definition.production_amount
+            * BigNumber::from(self.owned.max(0))
+            * self.production_multiplier
+            * (self.milestone_multiplier() * self.efficiency)
+    }
+
+    /// Product of every milestone reached so far.
+    pub fn milestone_multiplier(&self) -> f64 {
+        self.definition
+            .milestones
+            .iter()
+            .filter(|milestone| self.owned >= milestone.owned)
+            .map(|milestone| milestone.multiplier)
+            .product()
+    }
+
+    /// The lowest milestone not reached yet.
+    pub fn next_milestone(&self) -> Option<&Milestone> {
+        self.definition
+            .milestones
+            .iter()
+            .filter(|milestone| self.owned < milestone.owned)
+            .min_by_key(|milestone| milestone.owned)
+    }
+
+    /// Milestones reached by going from `before` copies to those owned now.
+    pub fn milestones_crossed(&self, before: i64) -> impl Iterator<Item = &Milestone> {
+        self.definition
+            .milestones
+            .iter()
+            .filter(move |milestone| before < milestone.owned && milestone.owned <= self.owned)
+    }
+
+    /// Expected share of each payout lost to risk: the payout itself when the
+    /// period fails, plus the fine. Only the first risk to fail in a period counts.
+    pub fn risk_loss(&self) -> f64 {
+        let mut survive = 1.0;
+        let mut loss = 0.0;
+        for power_trait in self.traits() {
+            if let PowerTrait::Risk { chance, penalty } = power_trait {
+                let chance = chance.clamp(0.0, 1.0);
+                loss += survive * chance * (1.0 + penalty);
+                survive *= 1.0 - chance;
+            }
+        }
+        loss
+    }
+
+    /// Pwr/s after upkeep and expected fines, which never goes below zero.
+    pub fn power_per_second(&self) -> BigNumber {
+        let period = self.period();
+        if self.halted || self.out_of_fuel || period <= 0.0 {
+            return BigNumber::ZERO;
+        }
+        let upkeep = self.upkeep() * BigNumber::from(self.owned.max(0));
+        let expected = self.payout() * (1.0 - self.risk_loss()).max(0.0);
+        net(expected, upkeep) / period
+    }
+
+    /// Pwr/s one more copy would add at the current modifiers, after its upkeep and expected fines.
+    pub fn marginal_power_per_second(&self) -> BigNumber {
+        let period = self.period();
+        if self.halted || period <= 0.0 {
+            return BigNumber::ZERO;
+        }
+        let payout = self.definition.production_amount
+            * self.production_multiplier
+            * (self.milestone_multiplier() * self.efficiency * (1.0 - self.risk_loss()).max(0.0));
+        net(payout, self.upkeep()) / period
+    }
+
+    // ONE PERIOD AT A TIME, SINCE UPKEEP, DECAY AND RISK CAN ALL CHANGE BETWEEN THEM.
+    // PAST MAX_TRAIT_BATCHES PERIODS THEY RUN IN EQUAL BATCHES, SO A LONG STEP STAYS CHEAP
+    fn run_traits(
+        &mut self,
+        periods: u64,
+        total_power: &mut BigNumber,
+        rolls: &mut StdRng,
+        report: &mut StepReport,
+    ) -> BigNumber {
+        let mut earned = BigNumber::ZERO;
+        let mut paid = BigNumber::ZERO;
+        let mut fined = BigNumber::ZERO;
+        let mut failures = 0;
+        let upkeep = self.upkeep() * BigNumber::from(self.owned.max(0));
+        let batch = periods.div_ceil(MAX_TRAIT_BATCHES).max(1);
+        let mut left = periods;
+        while left > 0 {
+            let size = batch.min(left);
+            left -= size;
+            if !upkeep.is_zero() {
+                let cost = upkeep * size as f64;
+                self.out_of_fuel = *total_power < cost;
+                if self.out_of_fuel {
+                    continue;
+                }
+                *total_power -= cost;
+                paid += cost;
+            }
+            let payout = self.payout();
+            for power_trait in self.definition.traits.iter() {
+                if let PowerTrait::Decay {
+                    per_period, floor, ..
+                } = power_trait
+                {
+                    self.efficiency = (self.efficiency - per_period * size as f64)
+                        .max(*floor)
+                        .min(1.0);
+                }
+            }
+            // EACH RISK FAILS ITS SHARE OF THE PERIODS NO EARLIER RISK FAILED,
+            // A BATCH OF ONE IS A SINGLE ROLL
+            let mut working = size;
+            for power_trait in self.definition.traits.iter() {
+                let PowerTrait::Risk { chance, penalty } = power_trait else {
+                    continue;
+                };
+                if working == 0 {
+                    break;
+                }
+                let expected = working as f64 * chance.clamp(0.0, 1.0);
+                let mut failed = expected.floor() as u64;
+                if rolls.gen::<f64>() < expected.fract() {
+                    failed += 1;
+                }
+                let failed = failed.min(working);
+                if failed == 0 {
+                    continue;
+                }
+                working -= failed;
+                failures += failed;
+                let fine = (payout * (penalty * failed as f64)).min(*total_power);
+                *total_power -= fine;
+                fined += fine;
+            }
+            let made = payout * working as f64;
+            *total_power += made;
+            earned += made;
+        }
+        if !paid.is_zero() {
+            report.upkeep.push(Upkeep {
+                id: self.id().to_string(),
+                paid,
+            });
+        }
+        if failures > 0 {
+            report.failures.push(Failure {
+                id: self.id().to_string(),
+                failures,
+                fine: fined,
+            });
+        }
+        earned
+    }
+
+    /// Total price of the next `quantity` copies.
+    pub fn price(&self, quantity: i64) -> BigNumber {
+        self.definition
+            .cost_scaling
+            .total_price(self.definition.cost, self.owned, quantity)
+    }
+
+    /// Most copies `budget` buys, never past max owned.
+    pub fn max_affordable(&self, budget: BigNumber) -> i64 {
+        self.definition.cost_scaling.max_affordable(
+            self.definition.cost,
+            self.owned,
+            budget,
+            self.remaining(),
+        )
+    }
+}
+
+/// Why [`Economy::buy`] refused a purchase.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BuyError {
+    UnknownPower,
+    Locked,
+    MaxOwned,
+    InsufficientPower,
+}
+impl fmt::Display for BuyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use BuyError::*;
+
+        match self {
+            UnknownPower => write!(f, "Unknown Power"),
+            Locked => write!(f, "Power Not Unlocked"),
+            MaxOwned => write!(f, "Maximum Already Owned"),
+            InsufficientPower => write!(f, "Insufficient Power"),
+        }
+    }
+}
+
+/// What one power paid out during one [`Economy::step`].
+pub struct Payout {
+    pub id: String,
+    pub periods: u64,
+    pub earned: BigNumber,
+}
+
+/// What one power paid to run during one [`Economy::step`].
+pub struct Upkeep {
+    pub id: String,
+    pub paid: BigNumber,
+}
+
+/// The periods one power lost to its [`PowerTrait::Risk`] during one [`Economy::step`].
+pub struct Failure {
+    pub id: String,
+    pub failures: u64,
+    pub fine: BigNumber,
+}
+
+/// What happened during one [`Economy::step`].
+#[derive(Default)]
+pub struct StepReport {
+    pub payouts: Vec<Payout>,
+    pub unlocked: Vec<String>,
+    pub upkeep: Vec<Upkeep>,
+    pub failures: Vec<Failure>,
+}
+impl StepReport {
+    pub fn earned(&self) -> BigNumber {
+        self.payouts.iter().map(|payout| payout.earned).sum()
+    }
+
+    /// Everything upkeep and fines took out of the total.
+    pub fn lost(&self) -> BigNumber {
+        let upkeep: BigNumber = self.upkeep.iter().map(|upkeep| upkeep.paid).sum();
+        let fines: BigNumber = self.failures.iter().map(|failure| failure.fine).sum();
+        upkeep + fines
+    }
+}
+
+/// All economy state for one save.
+#[derive(Clone)]
+pub struct Economy {
+    powers: Vec<Power>,
+    total_power: BigNumber,
+    click_value: BigNumber,
+    unlock_inputs: UnlockInputs,
+    // EVERY RISK ROLL, SEEDED SO A RUN CAN BE REPLAYED
+    rolls: StdRng,
+}
+impl Default for Economy {
+    fn default() -> Self {
+        Self::new(&[])
+    }
+}
+impl Economy {
+    pub fn new(definitions: &[PowerDefinition]) -> Self {
+        Self {
+            powers: definitions.iter().cloned().map(Power::new).collect(),
+            total_power: BigNumber::ZERO,
+            click_value: BigNumber::ONE,
+            unlock_inputs: UnlockInputs::default(),
+            rolls: StdRng::seed_from_u64(0),
+        }
+    }
+
+    pub fn seed_rolls(&mut self, seed: u64) {
+        self.rolls = StdRng::seed_from_u64(seed);
+    }
+
+    /// Swaps in new definitions, keeping what the player has of every power that is still defined.
+    pub fn set_definitions(&mut self, definitions: &[PowerDefinition]) {
+        self.powers = definitions
+            .iter()
+            .map(|definition| match self.power(&definition.id) {
+                Some(power) => Power {
+                    definition: definition.clone(),
+                    ..power.clone()
+                },
+                None => Power::new(definition.clone()),
+            })
+            .collect();
+    }
+
+    pub fn powers(&self) -> &[Power] {
+        &self.powers
+    }
+
+    pub fn power(&self, id: &str) -> Option<&Power> {
+        self.powers.iter().find(|power| power.id() == id)
+    }
+
+    fn power_mut(&mut self, id: &str) -> Option<&mut Power> {
+        self.powers.iter_mut().find(|power| power.id() == id)
+    }
+
+    /// Every power the player has unlocked, in definition order.
+    pub fn unlocked(&self) -> impl Iterator<Item = &Power> {
+        self.powers.iter().filter(|power| power.unlocked)
+    }
+
+    pub fn total_power(&self) -> BigNumber {
+        self.total_power
+    }
+
+    pub fn set_total_power(&mut self, total_power: BigNumber) {
+        self.total_power = total_power;
+    }
+
+    pub fn add_power(&mut self, amount: BigNumber) {
+        self.total_power += amount;
+    }
+
+    /// Takes `amount` out of the total, or nothing if there is not enough.
+    pub fn spend(&mut self, amount: BigNumber) -> bool {
+        if self.total_power < amount {
+            return false;
+        }
+        self.total_power -= amount;
+        true
+    }
+
+    pub fn click_value(&self) -> BigNumber {
+        self.click_value
+    }
+
+    pub fn set_click_value(&mut self, click_value: BigNumber) {
+        self.click_value = click_value;
+    }
+
+    /// One plain click, worth the current click value.
+    pub fn click(&mut self) -> BigNumber {
+        self.total_power += self.click_value;
+        self.click_value
+    }
+
+    /// Returns false when `id` has no definition.
+    pub fn set_owned(&mut self, id: &str, owned: i64) -> bool {
+        self.power_mut(id)
+            .map(|power| power.owned = owned)
+            .is_some()
+    }
+
+    /// Returns false when `id` has no definition.
+    pub fn set_unlocked(&mut self, id: &str, unlocked: bool) -> bool {
+        self.power_mut(id)
+            .map(|power| power.unlocked = unlocked)
+            .is_some()
+    }
+
+    /// `production` multiplies every payout, `rate` multiplies the period, below 1.0 is faster.
+    pub fn set_modifiers(&mut self, id: &str, production: BigNumber, rate: f64) {
+        if let Some(power) = self.power_mut(id) {
+            power.production_multiplier = production;
+            power.rate_multiplier = rate;
+        }
+    }
+
+    /// Returns false when `id` has no definition.
+    pub fn set_efficiency(&mut self, id: &str, efficiency: f64) -> bool {
+        self.power_mut(id)
+            .map(|power| power.efficiency = efficiency.clamp(0.0, 1.0))
+            .is_some()
+    }
+
+    /// One maintenance click on `id`, restoring what its decay allows.
+    /// Returns false when there was nothing to repair.
+    pub fn maintain(&mut self, id: &str) -> bool {
+        let Some(power) = self.power_mut(id) else {
+            return false;
+        };
+        if power.owned <= 0 || !power.needs_maintenance() {
+            return false;
+        }
+        let repair: f64 = power
+            .traits()
+            .iter()
+            .map(|power_trait| match power_trait {
+                PowerTrait::Decay { repair, .. } => *repair,
+                _ => 0.0,
+            })
+            .sum();
+        power.efficiency = (power.efficiency + repair).min(1.0);
+        true
+    }
+
+    /// A halted power holds its progress and pays nothing until resumed.
+    pub fn set_halted(&mut self, id: &str, halted: bool) {
+        if let Some(power) = self.power_mut(id) {
+            power.halted = halted;
+        }
+    }
+
+    pub fn unlock_inputs(&self) -> &UnlockInputs {
+        &self.unlock_inputs
+    }
+
+    pub fn unlock_inputs_mut(&mut self) -> &mut UnlockInputs {
+        &mut self.unlock_inputs
+    }
+
+    /// Starts a fresh run: nothing owned, nothing unlocked, `head_start` in the bank.
+    pub fn reset_run(&mut self, head_start: BigNumber) {
+        for power in self.powers.iter_mut() {
+            power.owned = 0;
+            power.unlocked = false;
+            power.elapsed = 0.0;
+            power.efficiency = 1.0;
+            power.out_of_fuel = false;
+        }
+        self.total_power = head_start;
+        self.unlock_inputs.earned = BigNumber::ZERO;
+    }
+
+    /// Advances production by `dt` seconds, paying out every period that finished.
+    pub fn step(&mut self, dt: f64) -> StepReport {
+        let mut report = StepReport::default();
+        for power in self.powers.iter_mut() {
+            let period = power.period();
+            if power.halted || period <= 0.0 {
+                continue;
+            }
+            // LEFTOVER TIME CARRIES INTO THE NEXT PERIOD
+            power.elapsed += dt.max(0.0);
+            let periods = (power.elapsed / period).floor();
+            if periods < 1.0 {
+                continue;
+            }
+            power.elapsed -= periods * period;
+            let earned = if power.definition.traits.is_empty() {
+                let earned = power.payout() * periods;
+                self.total_power += earned;
+                earned
+            } else if power.owned > 0 {
+                power.run_traits(
+                    periods as u64,
+                    &mut self.total_power,
+                    &mut self.rolls,
+                    &mut report,
+                )
+            } else {
+                BigNumber::ZERO
+            };
+            if earned.is_zero() {
+                continue;
+            }
+            report.payouts.push(Payout {
+                id: power.id().to_string(),
+                periods: periods as u64,
+                earned,
+            });
+        }
+        report.unlocked = self.check_unlocks();
+        report
+    }
+
+    fn owned_counts(&self) -> HashMap<String, i64> {
+        self.powers
+            .iter()
+            .map(|power| (power.id().to_string(), power.owned))
+            .collect()
+    }
+
+    /// Unlocks every power whose condition is now met, returning the new ones.
+    pub fn check_unlocks(&mut self) -> Vec<String> {
+        let owned = self.owned_counts();
+        let context = UnlockContext {
+            inputs: &self.unlock_inputs,
+            owned: &owned,
+            held: self.total_power,
+        };
+        let unlocked: Vec<String> = self
+            .powers
+            .iter()
+            .filter(|power| !power.unlocked && power.definition.unlock.is_met(&context))
+            .map(|power| power.id().to_string())
+            .collect();
+        for id in unlocked.iter() {
+            self.set_unlocked(id, true);
+        }
+        unlocked
+    }
+
+    /// How close `id` is to unlocking, from 0.0 to 1.0.
+    pub fn unlock_progress(&self, id: &str) -> Option<f64> {
+        let power = self.power(id)?;
+        if power.unlocked {
+            return Some(1.0);
+        }
+        let owned = self.owned_counts();
+        let context = UnlockContext {
+            inputs: &self.unlock_inputs,
+            owned: &owned,
+            held: self.total_power,
+        };
+        Some(power.definition.unlock.progress(&context))
+    }
+
+    /// The locked power closest to unlocking and how close it is, if any can still unlock.
+    pub fn next_unlock(&self) -> Option<(&Power, f64)> {
+        let owned = self.owned_counts();
+        let context = UnlockContext {
+            inputs: &self.unlock_inputs,
+            owned: &owned,
+            held: self.total_power,
+        };
+        self.powers
+            .iter()
+            .filter(|power| !power.unlocked && power.definition.unlock.is_reachable())
+            .map(|power| (power, power.definition.unlock.progress(&context)))
+            // FURTHEST ALONG WINS, TIES GO TO THE EARLIER BUTTON
+            .max_by(|(a, a_progress), (b, b_progress)| {
+                a_progress
+                    .total_cmp(b_progress)
+                    .then(b.definition.order.cmp(&a.definition.order))
+            })
+    }
+
+    /// Tests any condition against the current run, e.g. a victory condition.
+    pub fn condition_met(&self, condition: &UnlockCondition) -> bool {
+        let owned = self.owned_counts();
+        let context = UnlockContext {
+            inputs: &self.unlock_inputs,
+            owned: &owned,
+            held: self.total_power,
+        };
+        condition.is_met(&context)
+    }
+
+    /// Buys `quantity` copies of `id` and returns what they cost.
+    pub fn buy(&mut self, id: &str, quantity: i64) -> Result<BigNumber, BuyError> {
+        let total_power = self.total_power;
+        let power = self.power_mut(id).ok_or(BuyError::UnknownPower)?;
+        if !power.unlocked {
+            return Err(BuyError::Locked);
+        }
+        if quantity <= 0 {
+            return Ok(BigNumber::ZERO);
+        }
+        if quantity > power.remaining() {
+            return Err(BuyError::MaxOwned);
+        }
+        let price = power.price(quantity);
+        if total_power < price {
+            return Err(BuyError::InsufficientPower);
+        }
+        power.owned += quantity;
+        self.total_power -= price;
+        Ok(price)
+    }
+
+    /// Current pwr/s across every producing power, with every modifier applied.
+    pub fn power_per_second(&self) -> BigNumber {
+        self.powers.iter().map(Power::power_per_second).sum()
+    }
+
+    /// Seconds of production until `quantity` copies of `id` are affordable.
+    /// `None` when nothing is producing, or `id` is unknown.
+    pub fn time_to_afford(&self, id: &str, quantity: i64) -> Option<f64> {
+        let price = self.power(id)?.price(quantity);
+        if self.total_power >= price {
+            return Some(0.0);
+        }
+        let power_per_second = self.power_per_second();
+        if power_per_second.is_zero() {
+            return None;
+        }
+        Some(((price - self.total_power) / power_per_second).to_f64())
+    }
+}
+
+// WHAT IS LEFT OF `gross` AFTER `cost`, NEVER BELOW ZERO
+fn net(gross: BigNumber, cost: BigNumber) -> BigNumber {
+    if cost >= gross {
+        BigNumber::ZERO
+    } else {
+        gross - cost
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn definition(id: &str, cost: f64, unlock: UnlockCondition) -> PowerDefinition {
+        PowerDefinition {
+            title: id.to_uppercase(),
+            id: id.to_string(),
+            legacy_id: None,
+            sprite_index: 0,
+            order: 0,
+            cost: BigNumber::new(cost),
+            production_amount: BigNumber::new(2.0),
+            production_rate: 1.0,
+            max_owned: BigNumber::new(5.0),
+            unlock,
+            cost_scaling: CostScaling::Flat,
+            milestones: Vec::new(),
+            traits: Vec::new(),
+        }
+    }
+
+    // ONE UNLOCKED "plant" WITH `owned` COPIES, PAYING 2 EVERY SECOND EACH
+    fn economy(owned: i64) -> Economy {
+        let mut economy = Economy::new(&[
+            definition("plant", 10.0, UnlockCondition::Always),
+            definition("reactor", 30.0, UnlockCondition::Never),
+        ]);
+        economy.set_unlocked("plant", true);
+        economy.set_owned("plant", owned);
+        economy
+    }
+
+    fn close(a: BigNumber, b: f64) -> bool {
+        (a.to_f64() - b).abs() < 1e-6 * b.max(1.0)
+    }
+
+    #[test]
+    fn step_pays_whole_periods_and_carries_the_rest() {
+        let mut economy = economy(3);
+
+        let report = economy.step(2.5);
+        assert_eq!(report.payouts.len(), 1);
+        assert_eq!(report.payouts[0].periods, 2);
+        assert_eq!(economy.total_power(), BigNumber::new(12.0));
+        assert_eq!(economy.power("plant").unwrap().progress(), 0.5);
+
+        // THE HALF PERIOD LEFT OVER FINISHES THE NEXT ONE
+        let report = economy.step(0.5);
+        assert_eq!(report.earned(), BigNumber::new(6.0));
+        assert_eq!(economy.total_power(), BigNumber::new(18.0));
+        assert_eq!(economy.power("plant").unwrap().progress(), 0.0);
+    }
+
+    #[test]
+    fn step_pays_nothing_for_a_partial_period() {
+        let mut economy = economy(3);
+        assert!(economy.step(0.9).payouts.is_empty());
+        assert!(economy.total_power().is_zero());
+    }
+
+    // ONE UNLOCKED "plant" WITH `owned` COPIES AND `traits`, PAYING 2 EVERY SECOND EACH
+    fn economy_with(owned: i64, traits: Vec<PowerTrait>) -> Economy {
+        let mut economy = Economy::new(&[PowerDefinition {
+            traits,
+            ..definition("plant", 10.0, UnlockCondition::Always)
+        }]);
+        economy.set_unlocked("plant", true);
+        economy.set_owned("plant", owned);
+        economy
+    }
+
+    #[test]
+    fn power_per_second_counts_expected_fines() {
+        // HALF THE PERIODS ARE EXPECTED LOST: 10% FAIL, EACH COSTING THE PAYOUT PLUS 4x IT
+        let economy = economy_with(
+            3,
+            vec![PowerTrait::Risk {
+                chance: 0.1,
+                penalty: 4.0,
+            }],
+        );
+        let plant = economy.power("plant").unwrap();
+
+        assert!((plant.risk_loss() - 0.5).abs() < 1e-9);
+        assert!(close(plant.power_per_second(), 3.0));
+        assert!(close(plant.marginal_power_per_second(), 1.0));
+    }
+
+    #[test]
+    fn long_steps_batch_trait_periods() {
+        let mut economy = economy_with(3, vec![PowerTrait::Upkeep(BigNumber::ONE)]);
+        economy.set_total_power(BigNumber::new(1e7));
+
+        // A MILLION PERIODS, EACH MAKING 6 AND COSTING 3
+        let report = economy.step(1e6);
+        assert_eq!(report.upkeep.len(), 1);
+        assert_eq!(report.upkeep[0].paid, BigNumber::new(3e6));
+        assert_eq!(report.earned(), BigNumber::new(6e6));
+        assert_eq!(economy.total_power(), BigNumber::new(1.3e7));
+    }
+
+    #[test]
+    fn failures_are_reported_once_per_step() {
+        let mut economy = economy_with(
+            3,
+            vec![PowerTrait::Risk {
+                chance: 1.0,
+                penalty: 1.0,
+            }],
+        );
+        economy.set_total_power(BigNumber::new(1e6));
+
+        let report = economy.step(5000.0);
+        assert_eq!(report.failures.len(), 1);
+        assert_eq!(report.failures[0].failures, 5000);
+        assert_eq!(report.failures[0].fine, BigNumber::new(30000.0));
+        assert!(report.payouts.is_empty());
+    }
+
+    #[test]
+    fn buy_refuses_without_enough_power() {
+        let mut economy = economy(0);
+        economy.set_total_power(BigNumber::new(15.0));
+
+        assert_eq!(economy.buy("plant", 2), Err(BuyError::InsufficientPower));
+        assert_eq!(economy.total_power(), BigNumber::new(15.0));
+        assert_eq!(economy.buy("plant", 1), Ok(BigNumber::new(10.0)));
+        assert_eq!(economy.total_power(), BigNumber::new(5.0));
+        assert_eq!(economy.power("plant").unwrap().owned(), 1);
+    }
+
+    #[test]
+    fn buy_stops_at_max_owned() {
+        let mut economy = economy(4);
+        economy.set_total_power(BigNumber::new(1000.0));
+
+        assert_eq!(economy.buy("plant", 2), Err(BuyError::MaxOwned));
+        assert_eq!(economy.buy("plant", 1), Ok(BigNumber::new(10.0)));
+        assert_eq!(economy.buy("plant", 1), Err(BuyError::MaxOwned));
+        assert_eq!(economy.power("plant").unwrap().owned(), 5);
+    }
+
+    #[test]
+    fn buy_refuses_locked_and_unknown_powers() {
+        let mut economy = economy(0);
+        economy.set_total_power(BigNumber::new(1000.0));
+
+        assert_eq!(economy.buy("reactor", 1), Err(BuyError::Locked));
+        assert_eq!(economy.buy("nothing", 1), Err(BuyError::UnknownPower));
+    }
+
+    #[test]
+    fn exponential_price() {
+        let scaling = CostScaling::Exponential(2.0);
+        let base = BigNumber::ONE;
+
+        // 1 + 2 + 4, THEN 4 + 8
+        assert!(close(scaling.total_price(base, 0, 3), 7.0));
+        assert!(close(scaling.total_price(base, 2, 2), 12.0));
+        assert_eq!(scaling.max_affordable(base, 0, BigNumber::new(7.5), 100), 3);
+        assert_eq!(scaling.max_affordable(base, 0, BigNumber::new(6.5), 100), 2);
+    }
+
+    #[test]
+    fn linear_price() {
+        let scaling = CostScaling::Linear(BigNumber::new(5.0));
+        let base = BigNumber::new(10.0);
+
+        // 20 + 25 + 30
+        assert_eq!(scaling.total_price(base, 2, 3), BigNumber::new(75.0));
+        assert_eq!(scaling.total_price(base, 2, 0), BigNumber::ZERO);
+        // 10 + 15 FITS, 10 + 15 + 20 DOES NOT
+        assert_eq!(
+            scaling.max_affordable(base, 0, BigNumber::new(30.0), 100),
+            2
+        );
+    }
+
+    #[test]
+    fn steps_price() {
+        let scaling = CostScaling::Steps(vec![(0, 1.0), (2, 10.0)]);
+        let base = BigNumber::ONE;
+
+        // 1 + 1 + 10 + 10
+        assert_eq!(scaling.total_price(base, 0, 4), BigNumber::new(22.0));
+        assert_eq!(scaling.total_price(base, 3, 2), BigNumber::new(20.0));
+        assert_eq!(
+            scaling.max_affordable(base, 0, BigNumber::new(12.0), 100),
+            3
+        );
+        assert_eq!(
+            scaling.max_affordable(base, 0, BigNumber::new(11.0), 100),
+            2
+        );
+    }
+
+    #[test]
+    fn max_affordable_respects_the_limit() {
+        let scaling = CostScaling::Flat;
+        let budget = BigNumber::new(1000.0);
+
+        assert_eq!(scaling.max_affordable(BigNumber::ONE, 0, budget, 5), 5);
+        assert_eq!(scaling.max_affordable(BigNumber::ONE, 0, budget, -1), 0);
+    }
+
+    #[test]
+    fn time_to_afford() {
+        // 3 COPIES MAKE 6 PWR/S, A REACTOR COSTS 30
+        let mut economy = economy(3);
+        assert_eq!(economy.time_to_afford("reactor", 1), Some(5.0));
+
+        economy.set_total_power(BigNumber::new(18.0));
+        assert_eq!(economy.time_to_afford("reactor", 1), Some(2.0));
+
+        economy.set_total_power(BigNumber::new(30.0));
+        assert_eq!(economy.time_to_afford("reactor", 1), Some(0.0));
+
+        assert_eq!(economy.time_to_afford("nothing", 1), None);
+    }
+
+    #[test]
+    fn time_to_afford_without_production() {
+        let economy = economy(0);
+        assert_eq!(economy.time_to_afford("reactor", 1), None);
+    }
+
+    #[test]
+    fn check_unlocks_reports_each_power_once() {
+        let mut economy = Economy::new(&[
+            definition("plant", 10.0, UnlockCondition::Always),
+            definition(
+                "reactor",
+                30.0,
+                UnlockCondition::Earned(BigNumber::new(100.0)),
+            ),
+        ]);
+
+        assert_eq!(economy.check_unlocks(), vec!["plant".to_string()]);
+        assert!(economy.check_unlocks().is_empty());
+
+        economy.unlock_inputs_mut().earned = BigNumber::new(99.0);
+        assert!(economy.check_unlocks().is_empty());
+        assert_eq!(economy.unlock_progress("reactor"), Some(0.99));
+
+        economy.unlock_inputs_mut().earned = BigNumber::new(100.0);
+        assert_eq!(economy.check_unlocks(), vec!["reactor".to_string()]);
+        assert!(economy.power("reactor").unwrap().unlocked());
+    }
+
+    #[test]
+    fn step_reports_new_unlocks() {
+        let mut economy = economy(1);
+        economy.set_definitions(&[
+            definition("plant", 10.0, UnlockCondition::Always),
+            definition(
+                "reactor",
+                30.0,
+                UnlockCondition::Owned {
+                    power: "plant".to_string(),
+                    amount: 2,
+                },
+            ),
+        ]);
+        assert!(economy.step(1.0).unlocked.is_empty());
+
+        economy.set_owned("plant", 2);
+        assert_eq!(economy.step(1.0).unlocked, vec!["reactor".to_string()]);
+    }
+
+    #[test]
+    fn condition_met_reads_the_bank_and_inputs() {
+        let mut economy = economy(0);
+        let held = UnlockCondition::Held(BigNumber::new(50.0));
+        let earned = UnlockCondition::Earned(BigNumber::new(50.0));
+
+        economy.set_total_power(BigNumber::new(50.0));
+        assert!(economy.condition_met(&held));
+        assert!(!economy.condition_met(&earned));
+
+        // SPENDING LOWERS WHAT IS HELD BUT NOT WHAT WAS EARNED
+        economy.unlock_inputs_mut().earned = BigNumber::new(50.0);
+        assert!(economy.spend(BigNumber::new(10.0)));
+        assert!(!economy.condition_met(&held));
+        assert!(economy.condition_met(&earned));
+        assert!(!economy.condition_met(&UnlockCondition::Never));
+    }
+}
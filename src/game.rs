@@ -1,16 +1,35 @@
-use bevy::{prelude::*, utils::hashbrown::HashMap};
+use bevy::{ecs::system::SystemParam, prelude::*, utils::hashbrown::HashMap};
+use rand::Rng;
 use serde::{Deserialize, Serialize};
 use std::{io::Result, time::Duration};
 
 use crate::{
-    loading::{BackgroundAssets, PowerAssets, UiAssets},
+    achievements::Achievements,
+    click::{ClickValue, Combo},
+    economy::{self, Economy, PowerTrait},
+    loading::{BackgroundAssets, DataAssets, PowerAssets, PowerDefinitions, UiAssets},
+    number::BigNumber,
+    prestige::Prestige,
     save::{format_load, format_save, Save, Saveable},
     settings::Settings,
+    statistics::Statistics,
+    synergy::Synergies,
     ui::*,
-    AppState, Cost, CurrentOwned, MaxOwned, PauseState, ProdAmount, ProdRate, ProdTimer, Title,
-    UnlockBound, ID,
+    upgrades::{PurchasedUpgrades, UpgradeModifiers},
+    world_events::{Blackout, WorldModifiers},
+    AppState, CurrentOwned, GameRng, PauseState, Title, ID,
 };
 
+// FIXED STEPS PER SECOND FOR PRODUCTION AND AUTO-CLICKS
+const PRODUCTION_HZ: f64 = 60.0;
+// A HITCH UP TO THIS LONG IS CAUGHT UP IN FULL, AT MOST 120 FIXED STEPS IN ONE FRAME.
+// LONGER GAPS ARE LEFT TO OFFLINE PROGRESS
+const MAX_CATCH_UP_SECS: u64 = 2;
+// PERIODS SHORTER THAN THIS ARE DRAWN AS A SOLID BAR INSTEAD OF A FLICKER
+const CONTINUOUS_PERIOD_SECS: f64 = 0.1;
+// PERIODS AT LEAST THIS LONG GET A COUNTDOWN ON THEIR BAR
+const COUNTDOWN_PERIOD_SECS: f64 = 10.0;
+
 pub struct GameLoopPlugin;
 impl Plugin for GameLoopPlugin {
     fn name(&self) -> &str {
@@ -19,29 +38,67 @@ impl Plugin for GameLoopPlugin {
 
     fn build(&self, app: &mut App) {
         app.add_event::<SpawnPowerButton>()
+            .add_event::<PowerEarned>()
+            .add_event::<PowerLost>()
+            .add_event::<PowerDefinitionsReloaded>()
+            .add_systems(OnExit(AppState::Loading), load_power_definitions)
+            .add_systems(Update, reload_power_definitions)
             .add_systems(OnEnter(AppState::Playing), startup)
             .add_systems(OnExit(AppState::Playing), cleanup)
             .add_systems(OnEnter(PauseState::Paused), pause_startup)
             .add_systems(OnExit(PauseState::Paused), pause_cleanup)
             .add_systems(Update, pause_click.run_if(in_state(AppState::Playing)))
+            .init_resource::<BuyAmount>()
+            .init_resource::<PowerPerSecond>()
+            .init_resource::<GameEconomy>()
             .add_systems(
                 Update,
                 (
-                    evr_spawn_power_button,
+                    (evr_spawn_power_button, sort_power_buttons).chain(),
                     screen_click,
                     update_power_text,
+                    update_power_per_second_text,
+                    update_next_unlock_text,
+                    update_power_progress,
                     power_click,
-                    tick_power_timers,
-                    add_to_total_power,
-                    check_power_unlock_flags,
+                    maintenance_click,
+                    buy_amount_click,
+                    update_buy_amount_text,
+                    update_power_info_text,
+                    (announce_milestones, sync_power_entities).chain(),
+                    // THE TOTAL IS READ BY THE SHARE IN EACH BUTTON'S INFO
+                    update_power_per_second
+                        .before(update_power_info_text)
+                        .before(update_power_per_second_text),
+                )
+                    .run_if(in_state(PauseState::Unpaused)),
+            )
+            // PRODUCTION RUNS ON A FIXED CLOCK SO OUTPUT NEVER DEPENDS ON FRAME RATE
+            .insert_resource(Time::<Fixed>::from_hz(PRODUCTION_HZ))
+            .insert_resource(Time::<Virtual>::from_max_delta(Duration::from_secs(
+                MAX_CATCH_UP_SECS,
+            )))
+            .add_systems(
+                FixedUpdate,
+                (
+                    update_economy_inputs,
+                    update_unlock_inputs,
+                    step_economy,
                     auto_click,
                 )
+                    .chain()
                     .run_if(in_state(PauseState::Unpaused)),
             )
-            .add_systems(Update, save_button.run_if(in_state(PauseState::Paused)))
-            .insert_resource(PowerUnlockFlags::load("power_unlocks.ron").unwrap_or_default())
-            .insert_resource(Powers::load("powers.ron").unwrap_or_default())
-            .insert_resource(TotalPower::load("total_power.ron").unwrap_or_default());
+            .add_systems(
+                Update,
+                (
+                    save_button,
+                    prestige_button,
+                    statistics_button,
+                    automation_button,
+                )
+                    .run_if(in_state(PauseState::Paused)),
+            );
     }
 }
 
@@ -51,17 +108,8 @@ struct CleanupGame;
 #[derive(Component)]
 struct CleanupPause;
 
-#[derive(Default, Deref, DerefMut, Deserialize, Resource, Serialize)]
-pub struct TotalPower(i64);
-impl TotalPower {
-    fn add_power(&mut self, i: i64) {
-        self.0 += i;
-    }
-
-    fn power(&self) -> i64 {
-        self.0
-    }
-}
+#[derive(Default, Deserialize, Serialize)]
+pub struct TotalPower(BigNumber);
 impl Saveable for TotalPower {
     fn save(&self, filename: &str) -> Result<()> {
         format_save(self, filename)
@@ -75,6 +123,94 @@ impl Saveable for TotalPower {
     }
 }
 
+/// Sent after hot reload swaps in edited power definitions, so data that
+/// names powers can be checked again.
+#[derive(Event)]
+pub struct PowerDefinitionsReloaded;
+
+/// Current pwr/s across every producing power, with every production bonus.
+#[derive(Default, Deref, Resource)]
+pub struct PowerPerSecond(BigNumber);
+
+/// Everything outside the economy that unlock conditions read.
+#[derive(SystemParam)]
+pub struct UnlockSources<'w> {
+    prestige: Res<'w, Prestige>,
+    achievements: Res<'w, Achievements>,
+    statistics: Res<'w, Statistics>,
+    purchased_upgrades: Res<'w, PurchasedUpgrades>,
+}
+
+/// The running economy. Systems here feed it input every frame and mirror
+/// what it owns back onto the power entities other plugins query.
+#[derive(Default, Deref, DerefMut, Resource)]
+pub struct GameEconomy(Economy);
+impl GameEconomy {
+    pub fn owned(&self) -> HashMap<String, i64> {
+        self.powers()
+            .iter()
+            .map(|power| (power.id().to_string(), power.owned()))
+            .collect()
+    }
+
+    // PRESTIGE AND WORLD BONUSES APPLY TO EVERY POWER ALIKE, SO THEY COME IN AS `global`
+    pub fn apply_modifiers(
+        &mut self,
+        modifiers: &UpgradeModifiers,
+        synergies: &Synergies,
+        global: BigNumber,
+    ) {
+        let owned = self.owned();
+        for id in owned.keys() {
+            let production = global * (modifiers.production(id) * synergies.multiplier(id, &owned));
+            self.set_modifiers(id, production, modifiers.rate(id));
+        }
+    }
+
+    // UNLOCK CONDITIONS CAN TEST PROGRESS THE ECONOMY DOES NOT TRACK ITSELF
+    pub fn update_unlock_inputs(&mut self, sources: &UnlockSources) {
+        let inputs = self.unlock_inputs_mut();
+        inputs.earned = sources.prestige.run_earned();
+        inputs.lifetime_earned = sources.prestige.lifetime_earned();
+        inputs.rebirths = sources.prestige.rebirths();
+        inputs.clicks = sources.statistics.clicks();
+        inputs.upgrades = sources.purchased_upgrades.len();
+        // ACHIEVEMENTS ARE NEVER TAKEN AWAY, SO A MATCHING COUNT MEANS NOTHING NEW
+        let unlocked = sources.achievements.unlocked();
+        if inputs.achievements.len() != unlocked.len() {
+            inputs.achievements = unlocked.iter().cloned().collect();
+        }
+    }
+
+    pub fn total(&self) -> TotalPower {
+        TotalPower(self.total_power())
+    }
+
+    pub fn states(&self) -> PowerStates {
+        PowerStates(
+            self.powers()
+                .iter()
+                .map(|power| {
+                    let state = PowerState {
+                        owned: power.owned(),
+                        efficiency: power.efficiency(),
+                    };
+                    (power.id().to_string(), state)
+                })
+                .collect(),
+        )
+    }
+
+    pub fn unlock_flags(&self) -> PowerUnlockFlags {
+        PowerUnlockFlags(
+            self.powers()
+                .iter()
+                .map(|power| (power.id().to_string(), power.unlocked()))
+                .collect(),
+        )
+    }
+}
+
 #[derive(Component, Clone)]
 struct AutoClick(Timer);
 
@@ -84,23 +220,116 @@ struct Power;
 #[derive(Component, Deserialize, Serialize)]
 struct PowerText;
 
-#[derive(Bundle, Clone, Deserialize, Serialize)]
-pub struct PowerBundle {
-    power: Power,
-    title: Title,
-    id: ID,
-    cost: Cost,
-    production_amount: ProdAmount,
-    production_rate: ProdRate,
-    max_owned: MaxOwned,
-    current_owned: CurrentOwned,
-    unlock_bound: UnlockBound,
+#[derive(Component)]
+struct PowerPerSecondText;
+
+#[derive(Component)]
+struct NextUnlockText;
+
+// WHERE A POWER BUTTON SITS IN THE LIST, LOWEST FIRST
+#[derive(Component, Clone)]
+struct DisplayOrder(u32);
+
+#[derive(Component)]
+struct PowerTitleText;
+
+#[derive(Component)]
+struct PowerInfoText;
+
+#[derive(Component)]
+struct PowerMaxText;
+
+#[derive(Component)]
+struct PowerProgressFill;
+
+#[derive(Component)]
+struct PowerProgressText;
+
+// A POWER'S PROGRESS BAR, CLICKED FOR MAINTENANCE
+#[derive(Component)]
+struct MaintenanceButton(String);
+
+#[derive(Component)]
+struct BuyAmountText;
+
+#[derive(Clone, Copy, Default, PartialEq, Resource)]
+enum BuyAmount {
+    #[default]
+    One,
+    Ten,
+    Hundred,
+    Max,
 }
+impl BuyAmount {
+    fn next(&self) -> Self {
+        use BuyAmount::*;
 
-#[derive(Deref, DerefMut, Deserialize, Resource, Serialize)]
-pub struct Powers(Vec<PowerBundle>);
-impl Saveable for Powers {
-    fn save(&self, filename: &str) -> std::io::Result<()> {
+        match self {
+            One => Ten,
+            Ten => Hundred,
+            Hundred => Max,
+            Max => One,
+        }
+    }
+
+    fn label(&self) -> &str {
+        use BuyAmount::*;
+
+        match self {
+            One => "x1",
+            Ten => "x10",
+            Hundred => "x100",
+            Max => "MAX",
+        }
+    }
+
+    // HOLDING SHIFT OR CTRL OVERRIDES THE SELECTED AMOUNT
+    fn with_modifiers(&self, keys: &ButtonInput<KeyCode>) -> Self {
+        if keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) {
+            BuyAmount::Hundred
+        } else if keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+            BuyAmount::Ten
+        } else {
+            *self
+        }
+    }
+
+    // HOW MANY COPIES A PRESS WOULD BUY, NEVER PAST MAX OWNED
+    fn quantity(&self, power: &economy::Power, total_power: BigNumber) -> i64 {
+        use BuyAmount::*;
+
+        let remaining = power.remaining();
+        match self {
+            One => remaining.min(1),
+            Ten => remaining.min(10),
+            Hundred => remaining.min(100),
+            Max => power.max_affordable(total_power).max(remaining.min(1)),
+        }
+    }
+}
+
+/// What the player has of one power, the only per-power data a save keeps.
+#[derive(Clone, Deserialize, Serialize)]
+#[serde(default)]
+pub struct PowerState {
+    owned: i64,
+    // HOW WORN DOWN A DECAYING POWER IS, SO QUITTING NEVER REPAIRS IT
+    efficiency: f64,
+}
+impl Default for PowerState {
+    fn default() -> Self {
+        Self {
+            owned: 0,
+            efficiency: 1.0,
+        }
+    }
+}
+
+/// Per-power player state keyed by power ID.
+#[derive(Default, Deref, Deserialize, Serialize)]
+pub struct PowerStates(HashMap<String, PowerState>);
+impl Saveable for PowerStates {
+    fn save(&self, filename: &str) -> Result<()> {
         format_save(self, filename)
     }
 
@@ -111,125 +340,96 @@ impl Saveable for Powers {
         format_load(filename)
     }
 }
-impl Default for Powers {
-    fn default() -> Self {
-        Self(vec![
-            PowerBundle {
-                power: Power,
-                title: Title("Default Power".into()),
-                id: ID(0),
-                cost: Cost(0),
-                production_amount: ProdAmount(1),
-                production_rate: ProdRate(1000000.0),
-                max_owned: MaxOwned(1),
-                current_owned: CurrentOwned(0),
-                unlock_bound: UnlockBound(9_223_372_036_854_775_807),
-            },
-            PowerBundle {
-                power: Power,
-                title: Title("Middle School Science Project".into()),
-                id: ID(1),
-                cost: Cost(50),
-                production_amount: ProdAmount(5),
-                production_rate: ProdRate(5.0),
-                max_owned: MaxOwned(9_223_372_036_854_775_807),
-                current_owned: CurrentOwned(0),
-                unlock_bound: UnlockBound(50),
-            },
-            PowerBundle {
-                power: Power,
-                title: Title("Hamster on a Wheel".into()),
-                id: ID(2),
-                cost: Cost(1000),
-                production_amount: ProdAmount(25),
-                production_rate: ProdRate(1.0),
-                max_owned: MaxOwned(30_000_000),
-                current_owned: CurrentOwned(0),
-                unlock_bound: UnlockBound(1000),
-            },
-            PowerBundle {
-                power: Power,
-                title: Title("\'Gas\' Engine".into()),
-                id: ID(3),
-                cost: Cost(33_333),
-                production_amount: ProdAmount(100_000),
-                production_rate: ProdRate(240.0),
-                max_owned: MaxOwned(1),
-                current_owned: CurrentOwned(0),
-                unlock_bound: UnlockBound(10_000),
-            },
-            PowerBundle {
-                power: Power,
-                title: Title("Portable Generator".into()),
-                id: ID(4),
-                cost: Cost(242_424),
-                production_amount: ProdAmount(800),
-                production_rate: ProdRate(8.0),
-                max_owned: MaxOwned(9_223_372_036_854_775_807),
-                current_owned: CurrentOwned(0),
-                unlock_bound: UnlockBound(100_000),
-            },
-            PowerBundle {
-                power: Power,
-                title: Title("Hotwire the Neighbors".into()),
-                id: ID(5),
-                cost: Cost(999_999),
-                production_amount: ProdAmount(222),
-                production_rate: ProdRate(0.5),
-                max_owned: MaxOwned(128_000_000),
-                current_owned: CurrentOwned(0),
-                unlock_bound: UnlockBound(1_000_000),
-            },
-            PowerBundle {
-                power: Power,
-                title: Title("Electric Eel Farm".into()),
-                id: ID(6),
-                cost: Cost(2_500_000),
-                production_amount: ProdAmount(45_000),
-                production_rate: ProdRate(30.0),
-                max_owned: MaxOwned(10_000_000),
-                current_owned: CurrentOwned(0),
-                unlock_bound: UnlockBound(10_000_000),
-            },
-            PowerBundle {
-                power: Power,
-                title: Title("Miniscule Hadron Collider".into()),
-                id: ID(7),
-                cost: Cost(111_111_111),
-                production_amount: ProdAmount(123_456),
-                production_rate: ProdRate(33.0),
-                max_owned: MaxOwned(123_456_789),
-                current_owned: CurrentOwned(0),
-                unlock_bound: UnlockBound(100_000_000),
-            },
-            PowerBundle {
-                power: Power,
-                title: Title("Luke-warm Fusion Reactor".into()),
-                id: ID(8),
-                cost: Cost(987_654_321),
-                production_amount: ProdAmount(9_999),
-                production_rate: ProdRate(0.1),
-                max_owned: MaxOwned(1),
-                current_owned: CurrentOwned(0),
-                unlock_bound: UnlockBound(1_000_000_000),
-            },
-            PowerBundle {
-                power: Power,
-                title: Title("Buttered Cat Paradox".into()),
-                id: ID(9),
-                cost: Cost(1),
-                production_amount: ProdAmount(1),
-                production_rate: ProdRate(0.00001),
-                max_owned: MaxOwned(999),
-                current_owned: CurrentOwned(0),
-                unlock_bound: UnlockBound(10_000_000_000),
+impl PowerStates {
+    fn load_or_migrate(definitions: &PowerDefinitions) -> Self {
+        if let Ok(states) = Self::load("power_states.ron") {
+            return states;
+        }
+        // SAVES FROM BEFORE STRING IDS KEYED STATES BY NUMBER
+        let numeric = match LegacyPowerStates::load("power_states.ron") {
+            Ok(legacy) => legacy.0,
+            // SAVES FROM BEFORE THE SPLIT KEPT WHOLE POWERS IN POWERS.RON
+            Err(_) => match LegacyPowers::load("powers.ron") {
+                Ok(legacy) => legacy
+                    .0
+                    .into_iter()
+                    .map(|power| {
+                        let state = PowerState {
+                            owned: power.current_owned.0,
+                            ..default()
+                        };
+                        (power.id.0, state)
+                    })
+                    .collect(),
+                Err(_) => return Self::default(),
             },
-        ])
+        };
+        info!("[LOADED] Migrated Numeric Power States");
+        Self(migrate_ids(numeric, definitions))
+    }
+}
+
+// MAPS ANYTHING KEYED BY THE OLD NUMERIC IDS ONTO THE STRING IDS THAT REPLACED THEM
+fn migrate_ids<T>(
+    numeric: HashMap<usize, T>,
+    definitions: &PowerDefinitions,
+) -> HashMap<String, T> {
+    numeric
+        .into_iter()
+        .filter_map(|(legacy_id, value)| {
+            let definition = definitions
+                .iter()
+                .find(|definition| definition.legacy_id == Some(legacy_id));
+            if definition.is_none() {
+                info!("[ERROR] Legacy Power ID: {} Has No Definition", legacy_id);
+            }
+            definition.map(|definition| (definition.id.clone(), value))
+        })
+        .collect()
+}
+
+#[derive(Deserialize, Serialize)]
+struct LegacyPowerStates(HashMap<usize, PowerState>);
+impl Saveable for LegacyPowerStates {
+    fn save(&self, filename: &str) -> Result<()> {
+        format_save(self, filename)
+    }
+
+    fn load(filename: &str) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        format_load(filename)
     }
 }
 
-#[derive(Deserialize, Resource, Serialize)]
-pub struct PowerUnlockFlags(HashMap<usize, bool>);
+#[derive(Deserialize, Serialize)]
+struct LegacyId(usize);
+
+// ONLY WHAT THE MIGRATION NEEDS OUT OF AN OLD POWERS.RON, THE REST IS IGNORED
+#[derive(Deserialize, Serialize)]
+struct LegacyPower {
+    id: LegacyId,
+    current_owned: CurrentOwned,
+}
+
+#[derive(Deserialize, Serialize)]
+struct LegacyPowers(Vec<LegacyPower>);
+impl Saveable for LegacyPowers {
+    fn save(&self, filename: &str) -> Result<()> {
+        format_save(self, filename)
+    }
+
+    fn load(filename: &str) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        format_load(filename)
+    }
+}
+
+#[derive(Default, Deserialize, Serialize)]
+pub struct PowerUnlockFlags(HashMap<String, bool>);
 impl Saveable for PowerUnlockFlags {
     fn save(&self, filename: &str) -> std::io::Result<()> {
         format_save(self, filename)
@@ -242,26 +442,99 @@ impl Saveable for PowerUnlockFlags {
         format_load(filename)
     }
 }
-impl Default for PowerUnlockFlags {
-    fn default() -> Self {
-        PowerUnlockFlags(
-            (0..10) // CREATE RANGE
-                .map(|i| (i, false)) // MAP
-                .collect(), // COLLECT
-        )
+impl PowerUnlockFlags {
+    fn load_or_migrate(definitions: &PowerDefinitions) -> Self {
+        if let Ok(flags) = Self::load("power_unlocks.ron") {
+            return flags;
+        }
+        // SAVES FROM BEFORE STRING IDS KEYED FLAGS BY NUMBER
+        match LegacyPowerUnlockFlags::load("power_unlocks.ron") {
+            Ok(legacy) => {
+                info!("[LOADED] Migrated Numeric Power Unlock Flags");
+                Self(migrate_ids(legacy.0, definitions))
+            }
+            Err(_) => Self::default(),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+struct LegacyPowerUnlockFlags(HashMap<usize, bool>);
+impl Saveable for LegacyPowerUnlockFlags {
+    fn save(&self, filename: &str) -> std::io::Result<()> {
+        format_save(self, filename)
+    }
+
+    fn load(filename: &str) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        format_load(filename)
     }
 }
 
 #[derive(Event)]
-struct SpawnPowerButton(usize);
+struct SpawnPowerButton(String);
+
+/// Sent whenever power is produced or clicked, so runs can track what they earned.
+#[derive(Event)]
+pub struct PowerEarned {
+    pub amount: BigNumber,
+    pub source: EarnedFrom,
+}
+
+pub enum EarnedFrom {
+    // A PAYOUT FROM THE POWER WITH THIS ID
+    Power(String),
+    Click,
+    AutoClick,
+    Reward,
+}
+
+/// Sent whenever a power's traits take power out of the total.
+#[derive(Event)]
+pub struct PowerLost {
+    pub amount: BigNumber,
+    pub source: LostTo,
+}
+
+// EACH HOLDS THE ID OF THE POWER RESPONSIBLE
+pub enum LostTo {
+    Upkeep(String),
+    // ONE FINE CAN COVER SEVERAL FAILED PERIODS
+    Fine { id: String, failures: u64 },
+}
+
+/// Everything one step's upkeep and failures cost, logging each power that failed.
+pub fn power_lost_events(report: &economy::StepReport) -> Vec<PowerLost> {
+    let upkeep = report.upkeep.iter().map(|upkeep| PowerLost {
+        amount: upkeep.paid,
+        source: LostTo::Upkeep(upkeep.id.clone()),
+    });
+    let fines = report.failures.iter().map(|failure| {
+        info!(
+            "[EVENT] Power Failed -- ID: {} >> Failures: {} >> Fine: {}",
+            failure.id, failure.failures, failure.fine
+        );
+        PowerLost {
+            amount: failure.fine,
+            source: LostTo::Fine {
+                id: failure.id.clone(),
+                failures: failure.failures,
+            },
+        }
+    });
+    upkeep.chain(fines).collect()
+}
 
 fn startup(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     settings: Res<Settings>,
-    power_flags: Res<PowerUnlockFlags>,
+    economy: Res<GameEconomy>,
     background_assets: Res<BackgroundAssets>,
     ui_assets: Res<UiAssets>,
+    buy_amount: Res<BuyAmount>,
     mut evr_spawn_power_button: EventWriter<SpawnPowerButton>,
 ) {
     // SPAWN BACKGROUND SPRITE
@@ -309,20 +582,76 @@ fn startup(
                 ..default()
             },
             TextColor(text_color),
+            TextLayout::new_with_justify(JustifyText::Center),
             BackgroundColor(Color::NONE),
             CleanupGame,
         ))
+        .with_children(|parent| {
+            parent.spawn((
+                TextSpan::default(),
+                (
+                    TextFont {
+                        font: font.clone(),
+                        font_size,
+                        ..default()
+                    },
+                    TextColor(text_color),
+                ),
+                PowerText,
+            ));
+            // INCOME SITS ON ITS OWN LINE UNDER THE TOTAL
+            parent.spawn((
+                TextSpan::default(),
+                (
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 20.0,
+                        ..default()
+                    },
+                    TextColor(Pallette::Lighter.srgb()),
+                ),
+                PowerPerSecondText,
+            ));
+            parent.spawn((
+                TextSpan::default(),
+                (
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(Pallette::Lighter.srgb()),
+                ),
+                NextUnlockText,
+            ));
+        });
+
+    // SPAWN BUY AMOUNT BUTTON
+    let buy_amount_style = (
+        BorderColor(Pallette::Black.srgb()),
+        BorderRadius::all(Val::Percent(10.0)),
+        BackgroundColor(Pallette::Lighter.srgb()),
+    );
+
+    commands
+        .spawn((
+            BuyAmountButtonNode::node(),
+            BuyAmountButtonNode::marker(),
+            Button,
+            UIButton,
+            BuyAmountButton,
+            buy_amount_style,
+            CleanupGame,
+        ))
         .with_child((
-            TextSpan::default(),
-            (
-                TextFont {
-                    font: font.clone(),
-                    font_size,
-                    ..default()
-                },
-                TextColor(text_color),
-            ),
-            PowerText,
+            Text::new(format!("BUY {}", buy_amount.label())),
+            TextFont {
+                font: font.clone(),
+                font_size: 20.0,
+                ..default()
+            },
+            TextColor(Pallette::Black.srgb()),
+            BuyAmountText,
         ));
 
     // SPAWN PAUSE BUTTON
@@ -378,19 +707,18 @@ fn startup(
     info!("[SPAWNED] Game Nodes");
 
     // SPAWN POWER BUTTONS ALREADY UNLOCKED
-    for i in 0..power_flags.0.len() {
-        if let Some((_k, v)) = power_flags.0.get_key_value(&i) {
-            if *v {
-                evr_spawn_power_button.send(SpawnPowerButton(i));
-            }
-        }
+    for power in economy.unlocked() {
+        evr_spawn_power_button.send(SpawnPowerButton(power.id().to_string()));
     }
 
     // SPAWN AUTO-CLICK TIMER
-    commands.spawn(AutoClick(Timer::new(
-        Duration::from_secs_f64(0.125),
-        TimerMode::Repeating,
-    )));
+    commands.spawn((
+        AutoClick(Timer::new(
+            Duration::from_secs_f64(0.125),
+            TimerMode::Repeating,
+        )),
+        CleanupGame,
+    ));
 }
 
 fn cleanup(mut commands: Commands, query_entity: Query<Entity, With<CleanupGame>>) {
@@ -403,122 +731,172 @@ fn cleanup(mut commands: Commands, query_entity: Query<Entity, With<CleanupGame>
 fn evr_spawn_power_button(
     mut evr_spawn_power_button: EventReader<SpawnPowerButton>,
     mut query_parent_node: Query<Entity, With<UIButtonParentNode>>,
-    powers: Res<Powers>,
+    economy: Res<GameEconomy>,
     power_assets: Res<PowerAssets>,
     query_power: Query<&ID, With<Power>>,
     mut commands: Commands,
-    power_flags: Res<PowerUnlockFlags>,
     asset_server: Res<AssetServer>,
 ) {
     for ev in evr_spawn_power_button.read() {
-        // CHECK IF KEY/VALUE PAIR EXISTS
-        if let Some((_k, v)) = power_flags.0.get_key_value(&ev.0) {
+        // CHECK IF POWER EXISTS
+        if let Some(power) = economy.power(&ev.0) {
             // CHECK IF POWER IS UNLOCKED
-            if *v {
+            if power.unlocked() {
                 // CHECK IF POWER IS ALREADY SPAWNED
                 if !query_power.iter().any(|id| id.0 == ev.0) {
                     // SAFELY GET PARENT NODE ENTITY
                     if let Ok(parent_entity) = query_parent_node.get_single_mut() {
-                        if let Some(power) = powers.0.iter().find(|power| power.id.0 == ev.0) {
-                            // SPAWN AND INSERT POWER BUTTON
-                            let zero_style = (
-                                BorderColor(Color::NONE),
-                                BorderRadius::ZERO,
-                                BackgroundColor(Color::NONE),
-                            );
-
-                            let children = (
-                                zero_style,
-                                ImageNode::from_atlas_image(
-                                    power_assets.power_atlas.clone(),
-                                    TextureAtlas {
-                                        layout: power_assets.power_layout.clone(),
-                                        index: ev.0,
+                        let definition = power.definition();
+                        // SPAWN AND INSERT POWER BUTTON
+                        let zero_style = (
+                            BorderColor(Color::NONE),
+                            BorderRadius::ZERO,
+                            BackgroundColor(Color::NONE),
+                        );
+
+                        let children = (
+                            zero_style,
+                            ImageNode::from_atlas_image(
+                                power_assets.power_atlas.clone(),
+                                TextureAtlas {
+                                    layout: power_assets.power_layout.clone(),
+                                    index: definition.sprite_index,
+                                },
+                            ),
+                        );
+
+                        let grandchildren = (
+                            zero_style,
+                            ImageNode::from_atlas_image(
+                                power_assets.border_atlas.clone(),
+                                TextureAtlas {
+                                    layout: power_assets.border_layout.clone(),
+                                    index: 0,
+                                },
+                            ),
+                        );
+
+                        let slot_entity = commands
+                            .spawn((
+                                UIButtonPowerSlotNode::node(),
+                                DisplayOrder(definition.order),
+                            ))
+                            .id();
+                        let child_entity =
+                            commands.spawn((UIButtonPowerNode::node(), children)).id();
+                        let grandchild_entity = commands
+                            .spawn((
+                                UIButtonPowerNode::node(),
+                                UIButtonPowerNode::marker(),
+                                Button,
+                                UIButton,
+                                PowerButton,
+                                ID(ev.0.clone()),
+                                grandchildren,
+                            ))
+                            .id();
+
+                        let font = asset_server.load("fonts/PublicPixel.ttf");
+
+                        let info_text_entity = commands
+                            .spawn((UiButtonInfoNode::node(), zero_style))
+                            .with_children(|parent| {
+                                parent.spawn((
+                                    Text::new(power.title()),
+                                    TextFont {
+                                        font: font.clone(),
+                                        font_size: 10.0,
+                                        ..default()
                                     },
-                                ),
-                            );
-
-                            let grandchildren = (
-                                zero_style,
-                                ImageNode::from_atlas_image(
-                                    power_assets.border_atlas.clone(),
-                                    TextureAtlas {
-                                        layout: power_assets.border_layout.clone(),
-                                        index: 0,
+                                    TextColor(Pallette::White.srgb()),
+                                    PowerTitleText,
+                                    ID(ev.0.clone()),
+                                ));
+
+                                parent.spawn((
+                                    Text::new(power_info_text(
+                                        power,
+                                        1,
+                                        1.0,
+                                        economy.power_per_second(),
+                                    )),
+                                    TextFont {
+                                        font: font.clone(),
+                                        font_size: 10.0,
+                                        ..default()
+                                    },
+                                    TextColor(Pallette::Light.srgb()),
+                                    PowerInfoText,
+                                    ID(ev.0.clone()),
+                                ));
+
+                                parent.spawn((
+                                    Text::new(format!("MAX: {}", definition.max_owned)),
+                                    TextFont {
+                                        font: font.clone(),
+                                        font_size: 10.0,
+                                        ..default()
                                     },
-                                ),
-                            );
-
-                            let child_entity =
-                                commands.spawn((UIButtonPowerNode::node(), children)).id();
-                            let grandchild_entity = commands
-                                .spawn((
-                                    UIButtonPowerNode::node(),
-                                    UIButtonPowerNode::marker(),
-                                    Button,
-                                    UIButton,
-                                    PowerButton,
-                                    ID(ev.0),
-                                    grandchildren,
-                                ))
-                                .id();
-
-                            let font = asset_server.load("fonts/PublicPixel.ttf");
-
-                            let info_text_entity = commands
-                                .spawn((UiButtonInfoNode::node(), zero_style))
-                                .with_children(|parent| {
-                                    parent.spawn((
-                                        Text::new(power.title.0.clone()),
-                                        TextFont {
-                                            font: font.clone(),
-                                            font_size: 10.0,
-                                            ..default()
-                                        },
-                                        TextColor(Pallette::White.srgb()),
-                                    ));
-
-                                    parent.spawn((
-                                        Text::new(format!(
-                                            "COST: {}\nPROD: {}pwr/{}s",
-                                            power.cost.0,
-                                            power.production_amount.0,
-                                            power.production_rate.0
-                                        )),
-                                        TextFont {
-                                            font: font.clone(),
-                                            font_size: 10.0,
-                                            ..default()
-                                        },
-                                        TextColor(Pallette::Light.srgb()),
-                                    ));
-
-                                    parent.spawn((
-                                        Text::new(format!("MAX: {}", power.max_owned.0)),
-                                        TextFont {
-                                            font: font.clone(),
-                                            font_size: 10.0,
-                                            ..default()
-                                        },
-                                        TextColor(Pallette::White.srgb()),
-                                    ));
-                                })
-                                .id();
-
-                            commands
-                                .entity(grandchild_entity)
-                                .add_children(&[info_text_entity]);
-                            commands
-                                .entity(child_entity)
-                                .add_children(&[grandchild_entity]);
-                            commands.entity(parent_entity).add_children(&[child_entity]);
-
-                            // SPAWN ACTUAL POWER BUNDLE + INSERT TIMER
-                            commands.spawn(power.clone()).insert(ProdTimer::new(
-                                power.production_rate.0,
-                                TimerMode::Repeating,
-                            ));
-                        }
+                                    TextColor(Pallette::White.srgb()),
+                                    PowerMaxText,
+                                    ID(ev.0.clone()),
+                                ));
+                            })
+                            .id();
+
+                        commands
+                            .entity(grandchild_entity)
+                            .add_children(&[info_text_entity]);
+                        // PROGRESS BAR UNDER THE BUTTON, FILLED BY update_power_progress
+                        let progress_entity = commands
+                            .spawn((
+                                PowerProgressNode::node(),
+                                PowerProgressNode::marker(),
+                                BorderColor(Pallette::Black.srgb()),
+                                BackgroundColor(Pallette::Black.srgb()),
+                                // CLICKING THE BAR IS A MAINTENANCE CLICK
+                                Button,
+                                MaintenanceButton(ev.0.clone()),
+                            ))
+                            .with_children(|parent| {
+                                parent.spawn((
+                                    PowerProgressFillNode::node(),
+                                    PowerProgressFillNode::marker(),
+                                    BackgroundColor(Pallette::Dark.srgb()),
+                                    PowerProgressFill,
+                                    ID(ev.0.clone()),
+                                ));
+                                parent.spawn((
+                                    Text::default(),
+                                    TextFont {
+                                        font: font.clone(),
+                                        font_size: 8.0,
+                                        ..default()
+                                    },
+                                    TextColor(Pallette::White.srgb()),
+                                    PowerProgressText,
+                                    ID(ev.0.clone()),
+                                ));
+                            })
+                            .id();
+
+                        commands
+                            .entity(child_entity)
+                            .add_children(&[grandchild_entity]);
+                        commands
+                            .entity(slot_entity)
+                            .add_children(&[child_entity, progress_entity]);
+                        commands.entity(parent_entity).add_children(&[slot_entity]);
+
+                        // SPAWN THE POWER ENTITY, A VIEW OF THE ECONOMY THAT
+                        // IS DESPAWNED ON EXIT AND RESPAWNED FROM IT ON RE-ENTRY
+                        commands.spawn((
+                            Power,
+                            Title(power.title().to_string()),
+                            ID(ev.0.clone()),
+                            CurrentOwned(power.owned()),
+                            CleanupGame,
+                        ));
 
                         info!("[SPAWNED] Power + Button: {}", ev.0);
                     } else {
@@ -534,15 +912,246 @@ fn evr_spawn_power_button(
     }
 }
 
+// BUTTONS SPAWN IN UNLOCK ORDER, SO RESORT THEM WHENEVER ONE IS ADDED
+fn sort_power_buttons(
+    mut commands: Commands,
+    query_added: Query<(), Added<DisplayOrder>>,
+    query_parent_node: Query<(Entity, &Children), With<UIButtonParentNode>>,
+    query_order: Query<&DisplayOrder>,
+) {
+    if query_added.is_empty() {
+        return;
+    }
+    let Ok((parent_entity, children)) = query_parent_node.get_single() else {
+        return;
+    };
+    let mut sorted: Vec<Entity> = children.iter().copied().collect();
+    sorted.sort_by_key(|child| query_order.get(*child).map_or(u32::MAX, |order| order.0));
+    commands.entity(parent_entity).replace_children(&sorted);
+}
+
+// THE SAVE ONLY HOLDS PLAYER STATE, EVERYTHING ELSE COMES FROM THE DEFINITIONS
+pub fn load_power_definitions(
+    data_assets: Res<DataAssets>,
+    definitions: Res<Assets<PowerDefinitions>>,
+    sources: UnlockSources,
+    mut rng: ResMut<GameRng>,
+    mut economy: ResMut<GameEconomy>,
+) {
+    let Some(definitions) = definitions.get(&data_assets.power_definitions) else {
+        info!("[ERROR] Power Definitions Not Loaded");
+        return;
+    };
+    let mut loaded = Economy::new(definitions);
+    // RISK ROLLS FOLLOW THE GAMEPLAY SEED TOO
+    loaded.seed_rolls(rng.gen());
+    let total_power = TotalPower::load("total_power.ron").unwrap_or_default();
+    loaded.set_total_power(total_power.0);
+    for (id, state) in PowerStates::load_or_migrate(definitions).iter() {
+        if !loaded.set_owned(id, state.owned) {
+            info!("[ERROR] Saved Power: {} Has No Definition", id);
+        }
+        loaded.set_efficiency(id, state.efficiency);
+    }
+    for (id, unlocked) in PowerUnlockFlags::load_or_migrate(definitions).0.iter() {
+        loaded.set_unlocked(id, *unlocked);
+    }
+    economy.0 = loaded;
+    economy.update_unlock_inputs(&sources);
+    info!("[LOADED] Power Definitions: {}", economy.powers().len());
+}
+
+// HOT RELOAD: PUSH EDITED DESIGN DATA INTO THE ECONOMY, LEAVING OWNED COUNTS ALONE
+fn reload_power_definitions(
+    mut evr_asset: EventReader<AssetEvent<PowerDefinitions>>,
+    data_assets: Option<Res<DataAssets>>,
+    definitions: Res<Assets<PowerDefinitions>>,
+    mut economy: ResMut<GameEconomy>,
+    mut evw_reloaded: EventWriter<PowerDefinitionsReloaded>,
+    mut query_power: Query<(&ID, &mut Title), With<Power>>,
+    mut query_text: Query<
+        (&ID, &mut Text, Has<PowerTitleText>),
+        Or<(With<PowerTitleText>, With<PowerMaxText>)>,
+    >,
+) {
+    let Some(data_assets) = data_assets else {
+        return;
+    };
+    for ev in evr_asset.read() {
+        if !ev.is_modified(&data_assets.power_definitions) {
+            continue;
+        }
+        let Some(definitions) = definitions.get(&data_assets.power_definitions) else {
+            continue;
+        };
+
+        economy.set_definitions(definitions);
+
+        for (id, mut title) in query_power.iter_mut() {
+            if let Some(power) = economy.power(&id.0) {
+                title.0 = power.title().to_string();
+            }
+        }
+        for (id, mut text, is_title) in query_text.iter_mut() {
+            if let Some(power) = economy.power(&id.0) {
+                **text = if is_title {
+                    power.title().to_string()
+                } else {
+                    format!("MAX: {}", power.definition().max_owned)
+                };
+            }
+        }
+        evw_reloaded.send(PowerDefinitionsReloaded);
+        info!("[MODIFIED] Power Definitions Reloaded");
+    }
+}
+
+fn power_info_text(
+    power: &economy::Power,
+    quantity: i64,
+    synergy: f64,
+    total_output: BigNumber,
+) -> String {
+    let price = power.price(quantity);
+    let cost = match quantity {
+        0 => "MAXED".to_string(),
+        1 => format!("{price}"),
+        _ => format!("{price} (x{quantity})"),
+    };
+    let info = format!(
+        "COST: {}\nPROD: {}pwr/{}s",
+        cost,
+        power.definition().production_amount,
+        power.definition().production_rate
+    );
+    // ONLY MENTION SYNERGIES ONCE ANOTHER POWER IS ACTUALLY BOOSTING THIS ONE
+    let info = if synergy > 1.0 {
+        format!("{}\nSYN: +{:.0}%", info, (synergy - 1.0) * 100.0)
+    } else {
+        info
+    };
+    // THIS POWER'S SHARE OF THE PWR/S UNDER THE TOTAL, ONCE IT HAS ONE
+    let output = power.power_per_second();
+    let info = if output.is_zero() || total_output.is_zero() {
+        info
+    } else {
+        let share = (output / total_output).to_f64() * 100.0;
+        format!("{}\nOUT: {}pwr/s ({:.0}%)", info, output, share)
+    };
+    let info = match power.next_milestone() {
+        Some(milestone) => format!(
+            "{}\nNEXT MILESTONE AT {} (x{})",
+            info, milestone.owned, milestone.multiplier
+        ),
+        None => info,
+    };
+    power.traits().iter().fold(info, |info, power_trait| {
+        format!("{}\n{}", info, trait_text(power, power_trait))
+    })
+}
+
+fn trait_text(power: &economy::Power, power_trait: &PowerTrait) -> String {
+    match power_trait {
+        PowerTrait::Upkeep(amount) if power.out_of_fuel() => {
+            format!(
+                "FUEL: {}pwr/{}s, RAN OUT",
+                amount,
+                power.definition().production_rate
+            )
+        }
+        PowerTrait::Upkeep(amount) => {
+            format!(
+                "FUEL: {}pwr/{}s",
+                amount,
+                power.definition().production_rate
+            )
+        }
+        PowerTrait::Decay { .. } => {
+            format!("WEAR: {:.0}%, CLICK BAR TO FIX", power.efficiency() * 100.0)
+        }
+        PowerTrait::Risk { chance, penalty } => {
+            format!("RISK: {}% FOR x{} FINE", chance * 100.0, penalty)
+        }
+    }
+}
+
+fn update_power_info_text(
+    keys: Res<ButtonInput<KeyCode>>,
+    buy_amount: Res<BuyAmount>,
+    economy: Res<GameEconomy>,
+    synergies: Res<Synergies>,
+    power_per_second: Res<PowerPerSecond>,
+    mut query_info_text: Query<(&ID, &mut Text), With<PowerInfoText>>,
+) {
+    let buy_amount = buy_amount.with_modifiers(&keys);
+    let owned = economy.owned();
+    for (id, mut text) in query_info_text.iter_mut() {
+        let Some(power) = economy.power(&id.0) else {
+            continue;
+        };
+        // PREVIEW THE TOTAL COST OF THE SELECTED AMOUNT
+        let quantity = buy_amount.quantity(power, economy.total_power());
+        let info = power_info_text(
+            power,
+            quantity,
+            synergies.multiplier(&id.0, &owned),
+            **power_per_second,
+        );
+
+        // ONLY TOUCH THE TEXT WHEN IT ACTUALLY CHANGES
+        if **text != info {
+            **text = info;
+        }
+    }
+}
+
+fn buy_amount_click(
+    mut buy_amount: ResMut<BuyAmount>,
+    mut interaction_query: Query<&Interaction, (Changed<Interaction>, With<BuyAmountButton>)>,
+) {
+    for interaction in &mut interaction_query {
+        if *interaction == Interaction::Pressed {
+            *buy_amount = buy_amount.next();
+            info!("[MODIFIED] Buy Amount >> {}", buy_amount.label());
+        }
+    }
+}
+
+fn update_buy_amount_text(
+    keys: Res<ButtonInput<KeyCode>>,
+    buy_amount: Res<BuyAmount>,
+    mut query_buy_amount_text: Query<&mut Text, With<BuyAmountText>>,
+) {
+    let label = format!("BUY {}", buy_amount.with_modifiers(&keys).label());
+    for mut text in &mut query_buy_amount_text {
+        if **text != label {
+            **text = label.clone();
+        }
+    }
+}
+
 fn screen_click(
-    mut total_power: ResMut<TotalPower>,
+    click_value: Res<ClickValue>,
+    mut combo: ResMut<Combo>,
+    mut rng: ResMut<GameRng>,
+    mut economy: ResMut<GameEconomy>,
+    mut evw_power_earned: EventWriter<PowerEarned>,
     mut interaction_query: Query<&Interaction, (Changed<Interaction>, With<ScreenButton>)>,
 ) {
     for interaction in &mut interaction_query {
         if *interaction == Interaction::Pressed {
-            total_power.add_power(1);
-            info!("[EVENT] Click");
-            info!("[MODIFIED] Total Power: {}", total_power.0);
+            let (click, crit) = combo.click(click_value.value(), &mut rng);
+            economy.add_power(click);
+            evw_power_earned.send(PowerEarned {
+                amount: click,
+                source: EarnedFrom::Click,
+            });
+            if crit {
+                info!("[EVENT] Critical Click: +{}", click);
+            } else {
+                info!("[EVENT] Click");
+            }
+            info!("[MODIFIED] Total Power: {}", economy.total_power());
         }
     }
 }
@@ -555,7 +1164,7 @@ fn pause_click(
     for interaction in &mut interaction_query {
         if *interaction == Interaction::Pressed {
             match pause_state.get() {
-                PauseState::Paused => {
+                PauseState::Paused | PauseState::Statistics => {
                     next_state.set(PauseState::Unpaused);
                     info!("[MODIFIED] PauseState >> Unpaused");
                 }
@@ -569,12 +1178,94 @@ fn pause_click(
 }
 
 fn update_power_text(
-    total_power: Res<TotalPower>,
+    economy: Res<GameEconomy>,
     mut query_power_text: Query<&mut TextSpan, With<PowerText>>,
 ) {
-    if total_power.is_changed() {
+    if economy.is_changed() {
+        let total_power = format!("{}", economy.total_power());
         for mut span in &mut query_power_text {
-            **span = format!("{}", total_power.power());
+            if **span != total_power {
+                **span = total_power.clone();
+            }
+        }
+    }
+}
+
+fn update_power_per_second_text(
+    power_per_second: Res<PowerPerSecond>,
+    query_added: Query<(), Added<PowerPerSecondText>>,
+    mut query_text: Query<&mut TextSpan, With<PowerPerSecondText>>,
+) {
+    if power_per_second.is_changed() || !query_added.is_empty() {
+        for mut span in &mut query_text {
+            **span = format!("\n{} pwr/s", **power_per_second);
+        }
+    }
+}
+
+fn update_next_unlock_text(
+    economy: Res<GameEconomy>,
+    query_added: Query<(), Added<NextUnlockText>>,
+    mut query_text: Query<&mut TextSpan, With<NextUnlockText>>,
+) {
+    if !economy.is_changed() && query_added.is_empty() {
+        return;
+    }
+    // NOTHING TO SHOW ONCE EVERY REACHABLE POWER IS UNLOCKED
+    let next = match economy.next_unlock() {
+        Some((power, progress)) => {
+            format!("\nNEXT: {} {:.0}%", power.title(), progress * 100.0)
+        }
+        None => String::new(),
+    };
+    for mut span in &mut query_text {
+        if span.0 != next {
+            span.0 = next.clone();
+        }
+    }
+}
+
+// FILL EACH BAR FROM HOW FAR ITS POWER IS THROUGH THE CURRENT PERIOD
+fn update_power_progress(
+    economy: Res<GameEconomy>,
+    mut query_fill: Query<(&ID, &mut Node), With<PowerProgressFill>>,
+    mut query_text: Query<(&ID, &mut Text), With<PowerProgressText>>,
+) {
+    for (id, mut node) in &mut query_fill {
+        let Some(power) = economy.power(&id.0) else {
+            continue;
+        };
+        let fraction = if power.owned() <= 0 || power.halted() || power.out_of_fuel() {
+            0.0
+        } else if power.period() < CONTINUOUS_PERIOD_SECS {
+            1.0
+        } else {
+            power.progress()
+        };
+        let width = Val::Percent(fraction as f32 * 100.0);
+        if node.width != width {
+            node.width = width;
+        }
+    }
+
+    for (id, mut text) in &mut query_text {
+        let Some(power) = economy.power(&id.0) else {
+            continue;
+        };
+        let label = if power.owned() <= 0 {
+            String::new()
+        } else if power.out_of_fuel() {
+            "NO FUEL".to_string()
+        } else if power.needs_maintenance() {
+            format!("FIX {:.0}%", power.efficiency() * 100.0)
+        } else if !power.halted() && power.period() >= COUNTDOWN_PERIOD_SECS {
+            let remaining = power.period() * (1.0 - power.progress());
+            format!("{}s", remaining.ceil() as u64)
+        } else {
+            String::new()
+        };
+        if text.0 != label {
+            text.0 = label;
         }
     }
 }
@@ -616,11 +1307,69 @@ fn pause_startup(mut commands: Commands, asset_server: Res<AssetServer>) {
                     UIButtonChildNode::marker(),
                     Button,
                     UIButton,
+                    PauseMenuButton,
                     SaveExitButton,
                     children_style,
                 ))
                 .with_child((
                     Text::from("SAVE"),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 30.0,
+                        ..default()
+                    },
+                    TextColor(Pallette::Darker.srgb()),
+                ));
+            parent
+                .spawn((
+                    UIButtonChildNode::node(),
+                    UIButtonChildNode::marker(),
+                    Button,
+                    UIButton,
+                    PauseMenuButton,
+                    PrestigeButton,
+                    children_style,
+                ))
+                .with_child((
+                    Text::from("PRESTIGE"),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 30.0,
+                        ..default()
+                    },
+                    TextColor(Pallette::Darker.srgb()),
+                ));
+            parent
+                .spawn((
+                    UIButtonChildNode::node(),
+                    UIButtonChildNode::marker(),
+                    Button,
+                    UIButton,
+                    PauseMenuButton,
+                    StatisticsButton,
+                    children_style,
+                ))
+                .with_child((
+                    Text::from("STATISTICS"),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 30.0,
+                        ..default()
+                    },
+                    TextColor(Pallette::Darker.srgb()),
+                ));
+            parent
+                .spawn((
+                    UIButtonChildNode::node(),
+                    UIButtonChildNode::marker(),
+                    Button,
+                    UIButton,
+                    PauseMenuButton,
+                    AutomationButton,
+                    children_style,
+                ))
+                .with_child((
+                    Text::from("AUTOMATION"),
                     TextFont {
                         font,
                         font_size: 30.0,
@@ -640,78 +1389,173 @@ fn pause_cleanup(mut commands: Commands, query_entity: Query<Entity, With<Cleanu
 }
 
 fn power_click(
+    keys: Res<ButtonInput<KeyCode>>,
+    buy_amount: Res<BuyAmount>,
     query_interaction: Query<(&Interaction, &ID), (Changed<Interaction>, With<PowerButton>)>,
-    mut query_power: Query<(&ID, &Cost, &MaxOwned, &mut CurrentOwned), With<Power>>,
-    mut total_power: ResMut<TotalPower>,
+    mut economy: ResMut<GameEconomy>,
+    mut statistics: ResMut<Statistics>,
 ) {
-    for (interaction, interaction_id) in &query_interaction {
+    for (interaction, id) in &query_interaction {
         // ONLY RUN IF A POWER BUTTON IS PRESSED
         if *interaction == Interaction::Pressed {
-            for (power_id, cost, max_owned, mut current_owned) in query_power.iter_mut() {
-                // GRAB CORRESPONDING ENTITY + COMPONENTS
-                if power_id.0 == interaction_id.0 {
-                    // MAKE SURE YOU HAVE ENOUGH POWER
-                    if total_power.0 - cost.0 >= 0 {
-                        // MAKE SURE IT WOULD NOT PUT YOU OVER LIMIT
-                        if current_owned.0 < max_owned.0 {
-                            // DO THE THING
-                            total_power.0 -= cost.0;
-                            current_owned.0 += 1;
-                            info!(
-                                "[MODIFIED] Current Owned -- ID: {} >> Amt: {}",
-                                power_id.0, current_owned.0
-                            );
-                        } else {
-                            info!("[INVALID] Maximum Already Owned");
-                        }
-                    } else {
-                        info!("[INVALID] Insufficient Power");
+            let Some(power) = economy.power(&id.0) else {
+                continue;
+            };
+            // HOW MANY TO BUY, ALREADY CLAMPED TO MAX OWNED
+            let quantity = buy_amount
+                .with_modifiers(&keys)
+                .quantity(power, economy.total_power());
+            // MAKE SURE IT WOULD NOT PUT YOU OVER LIMIT
+            if quantity > 0 {
+                match economy.buy(&id.0, quantity) {
+                    Ok(price) => {
+                        statistics.record_purchase(price);
+                        info!(
+                            "[MODIFIED] Current Owned -- ID: {} >> Amt: {}",
+                            id.0,
+                            economy.power(&id.0).map_or(0, |power| power.owned())
+                        );
                     }
+                    Err(e) => info!("[INVALID] {}", e),
                 }
+            } else {
+                info!("[INVALID] Maximum Already Owned");
             }
         }
     }
 }
 
-fn tick_power_timers(mut query_timer: Query<&mut ProdTimer>, time: Res<Time>) {
-    for mut timer in query_timer.iter_mut() {
-        timer.0.tick(time.delta());
+fn maintenance_click(
+    query_interaction: Query<(&Interaction, &MaintenanceButton), Changed<Interaction>>,
+    mut economy: ResMut<GameEconomy>,
+) {
+    for (interaction, button) in &query_interaction {
+        if *interaction == Interaction::Pressed && economy.maintain(&button.0) {
+            info!(
+                "[MODIFIED] Maintained -- ID: {} >> Efficiency: {:.2}",
+                button.0,
+                economy
+                    .power(&button.0)
+                    .map_or(1.0, |power| power.efficiency())
+            );
+        }
     }
 }
 
-fn add_to_total_power(
-    mut query_timer: Query<(&mut ProdTimer, &ProdAmount, &CurrentOwned, &ID)>,
-    mut total_power: ResMut<TotalPower>,
+// EVERYTHING THE ECONOMY NEEDS FROM THE REST OF THE GAME BEFORE IT STEPS
+fn update_economy_inputs(
+    prestige: Res<Prestige>,
+    modifiers: Res<UpgradeModifiers>,
+    synergies: Res<Synergies>,
+    world_modifiers: Res<WorldModifiers>,
+    click_value: Res<ClickValue>,
+    mut economy: ResMut<GameEconomy>,
+    query_power: Query<(&ID, Has<Blackout>), With<Power>>,
 ) {
-    for (mut timer, prod_amount, current_owned, id) in query_timer.iter_mut() {
-        if timer.0.finished() {
-            let total_amount = prod_amount.0 * current_owned.0;
-            total_power.0 += total_amount;
-            timer.0.reset();
-            if total_amount > 0 {
-                info!(
-                    "[MODIFIED] Total Power +{} From Power: {}",
-                    total_amount, id.0
-                );
-            }
-        }
+    economy.apply_modifiers(
+        &modifiers,
+        &synergies,
+        prestige.production_multiplier() * world_modifiers.production(),
+    );
+    // BLACKED OUT POWERS HOLD THEIR PROGRESS UNTIL RESTORED
+    for (id, blackout) in query_power.iter() {
+        economy.set_halted(&id.0, blackout);
     }
+    economy.set_click_value(click_value.value());
+}
+
+fn update_unlock_inputs(sources: UnlockSources, mut economy: ResMut<GameEconomy>) {
+    economy.update_unlock_inputs(&sources);
 }
 
-fn check_power_unlock_flags(
-    mut power_flags: ResMut<PowerUnlockFlags>,
+// EVERY PERIOD THAT FINISHED THIS TICK IS PAID, LEFTOVER TIME CARRIES INTO THE NEXT
+fn step_economy(
+    time: Res<Time>,
+    mut economy: ResMut<GameEconomy>,
+    mut evw_power_earned: EventWriter<PowerEarned>,
+    mut evw_power_lost: EventWriter<PowerLost>,
     mut evw_spawn_power_button: EventWriter<SpawnPowerButton>,
-    powers: Res<Powers>,
-    total_power: Res<TotalPower>,
+    mut evw_toast: EventWriter<Toast>,
+    mut statistics: ResMut<Statistics>,
 ) {
-    for power in powers.0.iter() {
-        if let Some(flag) = power_flags.0.get_mut(&power.id.0) {
-            if total_power.0 >= power.unlock_bound.0 {
-                if !flag.to_owned() {
-                    *flag = true;
-                    evw_spawn_power_button.send(SpawnPowerButton(power.id.0));
-                    info!("[UNLOCKED] Power ID: {}", power.id.0);
-                }
+    let report = economy.step(time.delta_secs_f64());
+    for failure in report.failures.iter() {
+        let title = economy
+            .power(&failure.id)
+            .map_or(failure.id.as_str(), |power| power.title());
+        let times = match failure.failures {
+            1 => String::new(),
+            failures => format!(" x{failures}"),
+        };
+        evw_toast.send(Toast(format!(
+            "{} FAILED{}: -{}",
+            title, times, failure.fine
+        )));
+    }
+    evw_power_lost.send_batch(power_lost_events(&report));
+    for payout in report.payouts.iter() {
+        evw_power_earned.send(PowerEarned {
+            amount: payout.earned,
+            source: EarnedFrom::Power(payout.id.clone()),
+        });
+        info!(
+            "[MODIFIED] Total Power +{} From Power: {}",
+            payout.earned, payout.id
+        );
+    }
+    for id in report.unlocked {
+        statistics.record_unlock(&id);
+        info!("[UNLOCKED] Power ID: {}", id);
+        evw_spawn_power_button.send(SpawnPowerButton(id));
+    }
+}
+
+fn update_power_per_second(
+    economy: Res<GameEconomy>,
+    mut power_per_second: ResMut<PowerPerSecond>,
+) {
+    let per_second = economy.power_per_second();
+
+    // ONLY FLAG A CHANGE WHEN THE VALUE ACTUALLY MOVES
+    if power_per_second.0 != per_second {
+        power_per_second.0 = per_second;
+    }
+}
+
+// THE ENTITIES STILL HOLD THE OLD COUNT, SO EVERY WAY OF BUYING IS CAUGHT HERE
+fn announce_milestones(
+    economy: Res<GameEconomy>,
+    query_power: Query<(&ID, &CurrentOwned), With<Power>>,
+    mut evw_toast: EventWriter<Toast>,
+) {
+    for (id, current_owned) in query_power.iter() {
+        let Some(power) = economy.power(&id.0) else {
+            continue;
+        };
+        for milestone in power.milestones_crossed(current_owned.0) {
+            evw_toast.send(Toast(format!(
+                "MILESTONE: {} x{}",
+                power.title(),
+                milestone.multiplier
+            )));
+            info!(
+                "[UNLOCKED] Milestone: {} At {} Owned",
+                power.title(),
+                milestone.owned
+            );
+        }
+    }
+}
+
+// OTHER PLUGINS QUERY OWNED COUNTS OFF THE POWER ENTITIES
+fn sync_power_entities(
+    economy: Res<GameEconomy>,
+    mut query_power: Query<(&ID, &mut CurrentOwned), With<Power>>,
+) {
+    for (id, mut current_owned) in query_power.iter_mut() {
+        if let Some(power) = economy.power(&id.0) {
+            if current_owned.0 != power.owned() {
+                current_owned.0 = power.owned();
             }
         }
     }
@@ -720,66 +1564,67 @@ fn check_power_unlock_flags(
 fn save_button(
     mut query_interaction: Query<&Interaction, (Changed<Interaction>, With<SaveExitButton>)>,
     mut evw_save: EventWriter<Save>,
-    mut powers: ResMut<Powers>,
-    query_powers: Query<(
-        &Power,
-        &Title,
-        &ID,
-        &Cost,
-        &ProdAmount,
-        &ProdRate,
-        &MaxOwned,
-        &CurrentOwned,
-        &UnlockBound,
-    )>,
 ) {
     for interaction in &mut query_interaction {
         if *interaction == Interaction::Pressed {
-            for (
-                power,
-                title,
-                id,
-                cost,
-                production_amount,
-                production_rate,
-                max_owned,
-                current_owned,
-                unlock_bound,
-            ) in query_powers.iter()
-            {
-                for power_bundle in powers.0.iter_mut() {
-                    if power_bundle.id.0 == id.0 {
-                        power_bundle.power = power.to_owned();
-                        power_bundle.title.0 = title.0.to_owned();
-                        power_bundle.id.0 = id.0.to_owned();
-                        power_bundle.cost.0 = cost.0.to_owned();
-                        power_bundle.production_amount.0 = production_amount.0.to_owned();
-                        power_bundle.production_rate.0 = production_rate.0.to_owned();
-                        power_bundle.max_owned.0 = max_owned.0.to_owned();
-                        power_bundle.current_owned.0 = current_owned.0.to_owned();
-                        power_bundle.unlock_bound.0 = unlock_bound.0.to_owned();
-                    }
-
-                    info!("[MODIFIED] Power: {} -- Syncing", power_bundle.id.0);
-                }
-            }
             evw_save.send(Save);
         }
     }
 }
 
+fn prestige_button(
+    mut query_interaction: Query<&Interaction, (Changed<Interaction>, With<PrestigeButton>)>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    for interaction in &mut query_interaction {
+        if *interaction == Interaction::Pressed {
+            next_state.set(AppState::Prestige);
+            info!("[MODIFIED] AppState >> Prestige");
+        }
+    }
+}
+
+fn statistics_button(
+    mut query_interaction: Query<&Interaction, (Changed<Interaction>, With<StatisticsButton>)>,
+    mut next_state: ResMut<NextState<PauseState>>,
+) {
+    for interaction in &mut query_interaction {
+        if *interaction == Interaction::Pressed {
+            next_state.set(PauseState::Statistics);
+            info!("[MODIFIED] PauseState >> Statistics");
+        }
+    }
+}
+
+fn automation_button(
+    mut query_interaction: Query<&Interaction, (Changed<Interaction>, With<AutomationButton>)>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    for interaction in &mut query_interaction {
+        if *interaction == Interaction::Pressed {
+            next_state.set(AppState::Automation);
+            info!("[MODIFIED] AppState >> Automation");
+        }
+    }
+}
+
 fn auto_click(
     time: Res<Time>,
     settings: Res<Settings>,
-    mut total_power: ResMut<TotalPower>,
+    mut economy: ResMut<GameEconomy>,
+    mut evw_power_earned: EventWriter<PowerEarned>,
     mut query_auto_click: Query<&mut AutoClick>,
 ) {
     if settings.auto_click {
         if let Ok(mut auto_click) = query_auto_click.get_single_mut() {
             auto_click.0.tick(time.delta());
-            if auto_click.0.finished() {
-                total_power.0 += 1;
-                auto_click.0.reset();
+            // A REPEATING TIMER KEEPS ITS LEFTOVER, SO EVERY CLICK THAT CAME DUE IS PAID ONCE
+            for _ in 0..auto_click.0.times_finished_this_tick() {
+                let click = economy.click();
+                evw_power_earned.send(PowerEarned {
+                    amount: click,
+                    source: EarnedFrom::AutoClick,
+                });
                 info!("[EVENT] Auto-Click");
             }
         }
@@ -1,21 +1,46 @@
+mod achievements;
+pub mod auto_buy;
+mod automation;
+mod click;
+pub mod economy;
 mod game;
 mod loading;
 mod menu;
+pub mod number;
+mod offline;
+mod prestige;
 mod save;
 mod settings;
+mod statistics;
+pub mod synergy;
 mod ui;
+pub mod unlock;
+mod upgrades;
+mod victory;
+mod world_events;
 
-use std::{io::Cursor, time::Duration};
+use std::io::Cursor;
 
+use achievements::AchievementPlugin;
+use automation::AutomationPlugin;
 use bevy::{prelude::*, window::PrimaryWindow, winit::WinitWindows};
+use click::ClickPlugin;
 use game::GameLoopPlugin;
 use loading::LoadingPlugin;
 use menu::MenuPlugin;
+use offline::OfflinePlugin;
+use prestige::PrestigePlugin;
+use rand::{rngs::StdRng, SeedableRng};
 use save::SavePlugin;
 use serde::{Deserialize, Serialize};
 use settings::{Settings, SettingsPlugin};
+use statistics::StatisticsPlugin;
+use synergy::SynergyPlugin;
 use ui::{Pallette, UIPlugin};
+use upgrades::UpgradePlugin;
+use victory::VictoryPlugin;
 use winit::window::Icon;
+use world_events::WorldEventPlugin;
 
 pub struct GamePlugin;
 impl Plugin for GamePlugin {
@@ -47,14 +72,36 @@ impl Plugin for GamePlugin {
                 .set(ImagePlugin::default_nearest()),
         );
         app.add_plugins((
+            AchievementPlugin,
+            AutomationPlugin,
+            ClickPlugin,
             GameLoopPlugin,
             LoadingPlugin,
             MenuPlugin,
+            OfflinePlugin,
+            PrestigePlugin,
             SavePlugin,
+        ));
+        // add_plugins TAKES AT MOST 15 AT A TIME
+        app.add_plugins((
             SettingsPlugin,
+            StatisticsPlugin,
+            SynergyPlugin,
             UIPlugin,
+            UpgradePlugin,
+            VictoryPlugin,
+            WorldEventPlugin,
         ));
 
+        // SEED GAMEPLAY RNG, A FIXED SEED IN SETTINGS REPLAYS A RUN
+        let seed = app
+            .world()
+            .resource::<Settings>()
+            .rng_seed
+            .unwrap_or_else(rand::random);
+        info!("[INITIALIZED] RNG Seed: {}", seed);
+        app.insert_resource(GameRng::new(seed));
+
         app.add_sub_state::<PauseState>();
 
         app.add_systems(Startup, startup);
@@ -118,6 +165,11 @@ pub enum AppState {
     Menu,
     Playing,
     Settings,
+    Prestige,
+    Achievements,
+    Statistics,
+    Automation,
+    Victory,
     Exit,
 }
 
@@ -127,6 +179,17 @@ pub enum PauseState {
     #[default]
     Unpaused,
     Paused,
+    // THE STATISTICS OVERLAY, STILL PAUSED
+    Statistics,
+}
+
+/// Every gameplay roll goes through this, so a fixed seed reproduces a run.
+#[derive(Deref, DerefMut, Resource)]
+pub struct GameRng(StdRng);
+impl GameRng {
+    pub fn new(seed: u64) -> Self {
+        Self(StdRng::seed_from_u64(seed))
+    }
 }
 
 #[derive(Component, Clone, Deref, DerefMut, Deserialize, Serialize)]
@@ -138,41 +201,9 @@ impl Title {
 }
 
 #[derive(Component, Clone, Deref, DerefMut, Deserialize, Serialize)]
-pub struct ID(usize);
+pub struct ID(String);
 impl ID {
-    pub fn id(&self) -> &usize {
-        &self.0
-    }
-}
-
-#[derive(Component, Clone, Deref, DerefMut, Deserialize, Serialize)]
-pub struct Cost(i64);
-impl Cost {
-    pub fn cost(&self) -> &i64 {
-        &self.0
-    }
-}
-
-#[derive(Component, Clone, Deref, DerefMut, Deserialize, Serialize)]
-pub struct ProdAmount(i64);
-impl Cost {
-    pub fn prod_amt(&self) -> &i64 {
-        &self.0
-    }
-}
-
-#[derive(Component, Clone, Deref, DerefMut, Deserialize, Serialize)]
-pub struct ProdRate(f64);
-impl ProdRate {
-    pub fn prod_rate(&self) -> &f64 {
-        &self.0
-    }
-}
-
-#[derive(Component, Clone, Deref, DerefMut, Deserialize, Serialize)]
-pub struct MaxOwned(i64);
-impl MaxOwned {
-    pub fn max_owned(&self) -> &i64 {
+    pub fn id(&self) -> &String {
         &self.0
     }
 }
@@ -184,19 +215,3 @@ impl CurrentOwned {
         &self.0
     }
 }
-
-#[derive(Component, Clone, Deref, DerefMut, Deserialize, Serialize)]
-pub struct UnlockBound(i64);
-impl UnlockBound {
-    pub fn unlock_bound(&self) -> &i64 {
-        &self.0
-    }
-}
-
-#[derive(Component, Clone, Deref, DerefMut)]
-pub struct ProdTimer(Timer);
-impl ProdTimer {
-    pub fn new(secs: f64, mode: TimerMode) -> Self {
-        Self(Timer::new(Duration::from_secs_f64(secs), mode))
-    }
-}
@@ -1,7 +1,13 @@
-use bevy::prelude::*;
+use bevy::{
+    asset::{io::Reader, AssetLoader, LoadContext},
+    prelude::*,
+    scene::ron::de::from_bytes,
+};
 use bevy_asset_loader::prelude::*;
+use serde::Deserialize;
+use std::io::{Error, ErrorKind};
 
-use crate::AppState;
+use crate::{economy::PowerDefinition, AppState};
 
 pub struct LoadingPlugin;
 impl Plugin for LoadingPlugin {
@@ -10,10 +16,14 @@ impl Plugin for LoadingPlugin {
     }
 
     fn build(&self, app: &mut App) {
+        app.init_asset::<PowerDefinitions>()
+            .init_asset_loader::<PowerDefinitionsLoader>();
+
         app.add_loading_state(
             LoadingState::new(AppState::Loading)
                 .continue_to_state(AppState::Menu)
                 .load_collection::<BackgroundAssets>()
+                .load_collection::<DataAssets>()
                 .load_collection::<DogAssets>()
                 .load_collection::<MusicAssets>()
                 .load_collection::<PowerAssets>()
@@ -51,6 +61,12 @@ pub struct BackgroundAssets {
     pub room_background: Handle<Image>,
 }
 
+#[derive(AssetCollection, Resource)]
+pub struct DataAssets {
+    #[asset(path = "data/definitions.powers.ron")]
+    pub power_definitions: Handle<PowerDefinitions>,
+}
+
 #[allow(dead_code)] // TODO:
 #[derive(AssetCollection, Resource)]
 pub struct DogAssets {
@@ -95,3 +111,31 @@ pub struct MusicAssets {
     // from RPG Asset Pack - Soundtrack by Allan Oliveira
     pub title_music: Handle<AudioSource>,
 }
+
+/// Design data for every power, read from `*.powers.ron` so balance changes
+/// need neither a recompile nor a hand-edited save.
+#[derive(Asset, Deref, Deserialize, TypePath)]
+pub struct PowerDefinitions(Vec<PowerDefinition>);
+
+#[derive(Default)]
+struct PowerDefinitionsLoader;
+impl AssetLoader for PowerDefinitionsLoader {
+    type Asset = PowerDefinitions;
+    type Settings = ();
+    type Error = Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        from_bytes::<PowerDefinitions>(&bytes).map_err(|e| Error::new(ErrorKind::InvalidData, e))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["powers.ron"]
+    }
+}
@@ -29,6 +29,8 @@ struct CleanupMainMenu;
 #[derive(Component)]
 pub enum MainMenuButton {
     Play,
+    Achievements,
+    Statistics,
     Settings,
     Exit,
 }
@@ -61,15 +63,19 @@ fn startup(
     commands
         .spawn((UIButtonParentNode::node(), CleanupMainMenu))
         .with_children(|parent| {
-            for i in 0..3 {
+            for i in 0..5 {
                 let text: Text = match i {
                     0 => Text::new("Play"),
-                    1 => Text::new("Settings"),
+                    1 => Text::new("Achievements"),
+                    2 => Text::new("Statistics"),
+                    3 => Text::new("Settings"),
                     _ => Text::new("Exit"),
                 };
                 let mmb: MainMenuButton = match i {
                     0 => MainMenuButton::Play,
-                    1 => MainMenuButton::Settings,
+                    1 => MainMenuButton::Achievements,
+                    2 => MainMenuButton::Statistics,
+                    3 => MainMenuButton::Settings,
                     _ => MainMenuButton::Exit,
                 };
 
@@ -117,6 +123,14 @@ fn menu_button_interaction(
                     next_state.set(AppState::Playing);
                     info!("[MODIFIED] AppState >> Playing");
                 }
+                MainMenuButton::Achievements => {
+                    next_state.set(AppState::Achievements);
+                    info!("[MODIFIED] AppState >> Achievements");
+                }
+                MainMenuButton::Statistics => {
+                    next_state.set(AppState::Statistics);
+                    info!("[MODIFIED] AppState >> Statistics");
+                }
                 MainMenuButton::Settings => {
                     next_state.set(AppState::Settings);
                     info!("[MODIFIED] AppState >> Settings");
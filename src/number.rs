@@ -0,0 +1,535 @@
+use serde::{
+    de::{self, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::{
+    cmp::Ordering,
+    fmt,
+    iter::Sum,
+    ops::{Add, AddAssign, Div, Mul, MulAssign, Sub, SubAssign},
+};
+
+// LARGEST MANTISSA BEFORE WE SHIFT INTO THE EXPONENT
+// EVERY INTEGER BELOW THIS IS EXACT IN AN f64
+const MANTISSA_LIMIT: f64 = 1e15;
+const MANTISSA_DIGITS: i64 = 15;
+
+/// Non-negative number of arbitrary magnitude, stored as `mantissa * 10^exponent`.
+///
+/// Below 1e15 the exponent is always zero and the value is a plain `f64`, so
+/// everyday costs and totals stay exact. Above that the mantissa is kept in
+/// `[1e14, 1e15)` and the exponent grows instead. Every operation saturates:
+/// subtraction stops at zero and overflow stops at [`BigNumber::MAX`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct BigNumber {
+    mantissa: f64,
+    exponent: i64,
+}
+impl BigNumber {
+    pub const ZERO: Self = Self {
+        mantissa: 0.0,
+        exponent: 0,
+    };
+
+    pub const ONE: Self = Self {
+        mantissa: 1.0,
+        exponent: 0,
+    };
+
+    pub const MAX: Self = Self {
+        mantissa: MANTISSA_LIMIT - 1.0,
+        exponent: i64::MAX,
+    };
+
+    pub fn new(f: f64) -> Self {
+        Self::normalize(f, 0)
+    }
+
+    pub fn from_parts(mantissa: f64, exponent: i64) -> Self {
+        if exponent < 0 {
+            Self::normalize(mantissa / pow10(exponent.saturating_neg()), 0)
+        } else {
+            Self::normalize(mantissa, exponent)
+        }
+    }
+
+    /// Builds a number from its base-10 logarithm, e.g. `from_log10(20.0)` is 1e20.
+    pub fn from_log10(log: f64) -> Self {
+        if log.is_nan() {
+            return Self::ZERO;
+        }
+        if log < MANTISSA_DIGITS as f64 {
+            return Self::new(10f64.powf(log));
+        }
+        if log >= i64::MAX as f64 {
+            return Self::MAX;
+        }
+        let exponent = log.floor() as i64 - (MANTISSA_DIGITS - 1);
+        Self::normalize(10f64.powf(log - exponent as f64), exponent)
+    }
+
+    /// `base^exp` for a plain `f64` base that may overflow an `f64` result.
+    pub fn powf(base: f64, exp: f64) -> Self {
+        if base <= 0.0 {
+            return Self::ZERO;
+        }
+        Self::from_log10(base.log10() * exp)
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.mantissa == 0.0
+    }
+
+    pub fn floor(self) -> Self {
+        if self.exponent == 0 {
+            Self::new(self.mantissa.floor())
+        } else {
+            self
+        }
+    }
+
+    pub fn sqrt(self) -> Self {
+        // KEEP THE EXPONENT EVEN SO IT HALVES CLEANLY
+        if self.exponent % 2 == 0 {
+            Self::normalize(self.mantissa.sqrt(), self.exponent / 2)
+        } else {
+            Self::normalize((self.mantissa * 10.0).sqrt(), self.exponent / 2)
+        }
+    }
+
+    /// Whole part of the number, saturating at `i64::MAX`.
+    pub fn to_i64(self) -> i64 {
+        let whole = self.mantissa.floor() * pow10(self.exponent);
+        if whole >= i64::MAX as f64 {
+            i64::MAX
+        } else {
+            whole as i64
+        }
+    }
+
+    /// Nearest `f64`, infinite once the number outgrows one.
+    pub fn to_f64(self) -> f64 {
+        self.mantissa * pow10(self.exponent)
+    }
+
+    fn normalize(mantissa: f64, exponent: i64) -> Self {
+        if mantissa.is_nan() || mantissa <= 0.0 {
+            return Self::ZERO;
+        }
+        if mantissa.is_infinite() || exponent == i64::MAX {
+            return Self::MAX;
+        }
+
+        let (mut mantissa, mut exponent) = (mantissa, exponent);
+
+        // SHIFT LARGE MANTISSAS INTO THE EXPONENT
+        if mantissa >= MANTISSA_LIMIT {
+            let shift = mantissa.log10().floor() as i64 - (MANTISSA_DIGITS - 1);
+            mantissa /= pow10(shift);
+            exponent = exponent.saturating_add(shift);
+        }
+
+        // PULL SMALL MANTISSAS BACK OUT OF THE EXPONENT
+        if exponent > 0 && mantissa < MANTISSA_LIMIT / 10.0 {
+            let room = (MANTISSA_DIGITS - 1) - mantissa.log10().floor() as i64;
+            let shift = room.min(exponent);
+            mantissa *= pow10(shift);
+            exponent -= shift;
+        }
+
+        // CLEAN UP FLOATING POINT DRIFT AROUND THE BOUNDARY
+        if mantissa >= MANTISSA_LIMIT {
+            mantissa /= 10.0;
+            exponent = exponent.saturating_add(1);
+        }
+        if exponent > 0 && mantissa < MANTISSA_LIMIT / 10.0 {
+            mantissa *= 10.0;
+            exponent -= 1;
+        }
+
+        if exponent == i64::MAX {
+            return Self::MAX;
+        }
+
+        Self { mantissa, exponent }
+    }
+}
+
+fn pow10(exponent: i64) -> f64 {
+    10f64.powi(exponent.clamp(0, i32::MAX as i64) as i32)
+}
+
+/// Seconds as `Hh MMm SSs`.
+pub fn clock(secs: f64) -> String {
+    let secs = secs as u64;
+    format!(
+        "{}h {:02}m {:02}s",
+        secs / 3600,
+        (secs % 3600) / 60,
+        secs % 60
+    )
+}
+
+impl Eq for BigNumber {}
+impl Ord for BigNumber {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // NORMALIZED VALUES WITH A LARGER EXPONENT ARE ALWAYS LARGER
+        self.exponent.cmp(&other.exponent).then(
+            self.mantissa
+                .partial_cmp(&other.mantissa)
+                .unwrap_or(Ordering::Equal),
+        )
+    }
+}
+impl PartialOrd for BigNumber {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Add for BigNumber {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        let (big, small) = if self >= rhs {
+            (self, rhs)
+        } else {
+            (rhs, self)
+        };
+        let shift = big.exponent - small.exponent;
+        if shift > MANTISSA_DIGITS * 2 {
+            return big;
+        }
+        Self::normalize(big.mantissa + small.mantissa / pow10(shift), big.exponent)
+    }
+}
+impl AddAssign for BigNumber {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for BigNumber {
+    type Output = Self;
+
+    // SATURATES AT ZERO
+    fn sub(self, rhs: Self) -> Self {
+        if rhs >= self {
+            return Self::ZERO;
+        }
+        let shift = self.exponent - rhs.exponent;
+        if shift > MANTISSA_DIGITS * 2 {
+            return self;
+        }
+        Self::normalize(self.mantissa - rhs.mantissa / pow10(shift), self.exponent)
+    }
+}
+impl SubAssign for BigNumber {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Mul for BigNumber {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::normalize(
+            self.mantissa * rhs.mantissa,
+            self.exponent.saturating_add(rhs.exponent),
+        )
+    }
+}
+impl Mul<f64> for BigNumber {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self {
+        Self::normalize(self.mantissa * rhs, self.exponent)
+    }
+}
+impl MulAssign for BigNumber {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+impl MulAssign<f64> for BigNumber {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs;
+    }
+}
+
+impl Div for BigNumber {
+    type Output = Self;
+
+    // DIVIDING BY ZERO SATURATES AT MAX
+    fn div(self, rhs: Self) -> Self {
+        if self.is_zero() {
+            return Self::ZERO;
+        }
+        if rhs.is_zero() {
+            return Self::MAX;
+        }
+        Self::from_parts(
+            self.mantissa / rhs.mantissa,
+            self.exponent.saturating_sub(rhs.exponent),
+        )
+    }
+}
+impl Div<f64> for BigNumber {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self {
+        self / Self::new(rhs)
+    }
+}
+
+impl Sum for BigNumber {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, n| acc + n)
+    }
+}
+
+impl From<i64> for BigNumber {
+    fn from(i: i64) -> Self {
+        Self::new(i as f64)
+    }
+}
+impl From<f64> for BigNumber {
+    fn from(f: f64) -> Self {
+        Self::new(f)
+    }
+}
+
+impl fmt::Display for BigNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // PLAIN DIGITS UNTIL A MILLION, SCIENTIFIC AFTER
+        if self.exponent == 0 && self.mantissa < 1e6 {
+            if self.mantissa.fract() == 0.0 {
+                write!(f, "{:.0}", self.mantissa)
+            } else {
+                write!(f, "{:.2}", self.mantissa)
+            }
+        } else {
+            let s = format!("{:.3e}", self.mantissa);
+            let (digits, exp) = s.split_once('e').unwrap_or((&s, "0"));
+            let exp = exp
+                .parse::<i64>()
+                .unwrap_or(0)
+                .saturating_add(self.exponent);
+            write!(f, "{digits}e{exp}")
+        }
+    }
+}
+
+impl std::str::FromStr for BigNumber {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (mantissa, exponent) = match s.split_once(['e', 'E']) {
+            Some((m, e)) => (m, e),
+            None => (s, "0"),
+        };
+        let mantissa = mantissa
+            .parse::<f64>()
+            .map_err(|e| format!("invalid mantissa in \"{s}\": {e}"))?;
+        let exponent = exponent
+            .parse::<i64>()
+            .map_err(|e| format!("invalid exponent in \"{s}\": {e}"))?;
+        Ok(Self::from_parts(mantissa, exponent))
+    }
+}
+
+// SMALL VALUES ARE WRITTEN AS PLAIN NUMBERS SO SAVES STAY READABLE,
+// ANYTHING ABOVE 1e15 IS WRITTEN AS A "<mantissa>e<exponent>" STRING
+impl Serialize for BigNumber {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if self.exponent == 0 {
+            if self.mantissa.fract() == 0.0 {
+                serializer.serialize_i64(self.mantissa as i64)
+            } else {
+                serializer.serialize_f64(self.mantissa)
+            }
+        } else {
+            serializer.serialize_str(&format!("{}e{}", self.mantissa, self.exponent))
+        }
+    }
+}
+
+// ACCEPTS INTEGERS, FLOATS AND STRINGS SO OLD i64 SAVES STILL LOAD
+impl<'de> Deserialize<'de> for BigNumber {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(BigNumberVisitor)
+    }
+}
+
+struct BigNumberVisitor;
+impl Visitor<'_> for BigNumberVisitor {
+    type Value = BigNumber;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a number or a \"<mantissa>e<exponent>\" string")
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<BigNumber, E> {
+        Ok(BigNumber::from(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<BigNumber, E> {
+        Ok(BigNumber::new(v as f64))
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<BigNumber, E> {
+        Ok(BigNumber::new(v as f64))
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<BigNumber, E> {
+        Ok(BigNumber::new(v as f64))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<BigNumber, E> {
+        Ok(BigNumber::new(v))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<BigNumber, E> {
+        v.parse().map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::scene::ron::{from_str, to_string};
+
+    #[test]
+    fn sub_saturates_at_zero() {
+        assert_eq!(BigNumber::new(3.0) - BigNumber::new(5.0), BigNumber::ZERO);
+        assert_eq!(BigNumber::new(5.0) - BigNumber::new(5.0), BigNumber::ZERO);
+        assert_eq!(
+            BigNumber::from_parts(1.0, 20) - BigNumber::from_parts(2.0, 20),
+            BigNumber::ZERO
+        );
+        assert_eq!(
+            BigNumber::new(5.0) - BigNumber::new(3.0),
+            BigNumber::new(2.0)
+        );
+
+        let mut n = BigNumber::ONE;
+        n -= BigNumber::MAX;
+        assert!(n.is_zero());
+    }
+
+    #[test]
+    fn sub_ignores_what_is_too_small_to_show() {
+        let big = BigNumber::from_parts(1.0, 40);
+        assert_eq!(big - BigNumber::ONE, big);
+    }
+
+    #[test]
+    fn div_by_zero_saturates() {
+        assert_eq!(BigNumber::new(5.0) / BigNumber::ZERO, BigNumber::MAX);
+        assert_eq!(BigNumber::new(5.0) / 0.0, BigNumber::MAX);
+        assert_eq!(BigNumber::ZERO / BigNumber::ZERO, BigNumber::ZERO);
+        assert_eq!(BigNumber::new(6.0) / 3.0, BigNumber::new(2.0));
+    }
+
+    #[test]
+    fn normalize_at_the_mantissa_limit() {
+        // THE LAST EXACT INTEGER STAYS PLAIN, THE NEXT ONE SHIFTS INTO THE EXPONENT
+        let below = BigNumber::new(MANTISSA_LIMIT - 1.0);
+        assert_eq!(below.exponent, 0);
+        assert_eq!(below.to_i64(), 999_999_999_999_999);
+
+        let at = BigNumber::new(MANTISSA_LIMIT);
+        assert_eq!((at.mantissa, at.exponent), (1e14, 1));
+        assert_eq!(at.to_f64(), 1e15);
+
+        // THE SAME VALUE BUILT ANY WAY NORMALIZES THE SAME
+        assert_eq!(BigNumber::from_parts(1.0, 15), at);
+        assert_eq!(BigNumber::from_parts(1e16, -1), at);
+        assert_eq!(BigNumber::from_log10(15.0), at);
+    }
+
+    #[test]
+    fn normalize_pulls_small_mantissas_back() {
+        assert_eq!(BigNumber::from_parts(1.0, 3), BigNumber::new(1000.0));
+        assert_eq!(BigNumber::from_parts(1.0, 3).exponent, 0);
+        assert_eq!(BigNumber::from_parts(5.0, -1), BigNumber::new(0.5));
+
+        // DROPPING BACK UNDER THE LIMIT RETURNS TO A PLAIN f64
+        let n = BigNumber::new(MANTISSA_LIMIT) - BigNumber::ONE;
+        assert_eq!(n.exponent, 0);
+        assert_eq!(n, BigNumber::new(MANTISSA_LIMIT - 1.0));
+    }
+
+    #[test]
+    fn normalize_edge_values() {
+        assert_eq!(BigNumber::new(f64::NAN), BigNumber::ZERO);
+        assert_eq!(BigNumber::new(-1.0), BigNumber::ZERO);
+        assert_eq!(BigNumber::new(f64::INFINITY), BigNumber::MAX);
+        assert_eq!(BigNumber::from_parts(1.0, i64::MAX), BigNumber::MAX);
+        assert_eq!(BigNumber::MAX * BigNumber::MAX, BigNumber::MAX);
+    }
+
+    #[test]
+    fn ord_across_exponents() {
+        let plain = BigNumber::new(MANTISSA_LIMIT - 1.0);
+        let shifted = BigNumber::from_parts(1.0, 15);
+        assert!(shifted > plain);
+        assert!(BigNumber::from_parts(1.0, 21) > BigNumber::from_parts(9.0, 20));
+        assert!(BigNumber::from_parts(2.0, 20) > BigNumber::from_parts(1.0, 20));
+        assert!(BigNumber::MAX > BigNumber::from_parts(1.0, 1000));
+        assert!(BigNumber::ZERO < BigNumber::ONE);
+
+        let mut sorted = vec![shifted, BigNumber::ONE, plain, BigNumber::ZERO];
+        sorted.sort();
+        assert_eq!(
+            sorted,
+            vec![BigNumber::ZERO, BigNumber::ONE, plain, shifted]
+        );
+    }
+
+    #[test]
+    fn clock_pads_minutes_and_seconds() {
+        assert_eq!(clock(0.0), "0h 00m 00s");
+        assert_eq!(clock(3725.9), "1h 02m 05s");
+        assert_eq!(clock(100.0 * 3600.0), "100h 00m 00s");
+    }
+
+    #[test]
+    fn serde_plain_integer() {
+        // OLD SAVES WROTE AN i64
+        let n: BigNumber = from_str("1234").unwrap();
+        assert_eq!(n, BigNumber::new(1234.0));
+        assert_eq!(to_string(&n).unwrap(), "1234");
+        assert_eq!(from_str::<BigNumber>(&to_string(&n).unwrap()).unwrap(), n);
+
+        let n: BigNumber = from_str(&i64::MAX.to_string()).unwrap();
+        assert_eq!(n, BigNumber::new(i64::MAX as f64));
+
+        let n: BigNumber = from_str("2.5").unwrap();
+        assert_eq!(to_string(&n).unwrap(), "2.5");
+    }
+
+    #[test]
+    fn serde_string_above_the_limit() {
+        for n in [
+            BigNumber::new(MANTISSA_LIMIT),
+            BigNumber::from_parts(1.5, 20),
+            BigNumber::from_parts(123.0, 4000),
+            BigNumber::MAX,
+        ] {
+            let written = to_string(&n).unwrap();
+            assert!(written.starts_with('"'), "{written}");
+            assert_eq!(from_str::<BigNumber>(&written).unwrap(), n);
+        }
+        assert_eq!(
+            to_string(&BigNumber::from_parts(1.5, 20)).unwrap(),
+            "\"150000000000000e6\""
+        );
+        assert_eq!(
+            from_str::<BigNumber>("\"1e20\"").unwrap(),
+            BigNumber::from_parts(1.0, 20)
+        );
+    }
+}
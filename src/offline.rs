@@ -0,0 +1,334 @@
+use bevy::{ecs::system::SystemParam, prelude::*, ui::FocusPolicy, utils::SystemTime};
+use serde::{Deserialize, Serialize};
+use std::io::Result;
+
+use crate::{
+    game::{
+        load_power_definitions, power_lost_events, EarnedFrom, GameEconomy, LostTo, PowerEarned,
+        PowerLost,
+    },
+    number::BigNumber,
+    prestige::Prestige,
+    save::{format_load, format_save, Saveable},
+    settings::Settings,
+    statistics::Statistics,
+    synergy::Synergies,
+    ui::{DialogParentNode, OfflineDismissButton, Pallette, Toast, UIButton, UIButtonChildNode},
+    upgrades::UpgradeModifiers,
+    AppState,
+};
+
+pub struct OfflinePlugin;
+impl Plugin for OfflinePlugin {
+    fn name(&self) -> &str {
+        "Offline Plugin"
+    }
+
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnExit(AppState::Loading),
+            credit_offline_progress.after(load_power_definitions),
+        )
+        .add_systems(OnEnter(AppState::Playing), summary_startup)
+        .add_systems(OnExit(AppState::Playing), summary_cleanup)
+        .add_systems(Update, dismiss_click.run_if(in_state(AppState::Playing)));
+    }
+}
+
+#[derive(Component)]
+struct CleanupOfflineSummary;
+
+/// Wall-clock time of the last save, in seconds since the unix epoch.
+#[derive(Deserialize, Serialize)]
+pub struct LastSeen(u64);
+impl LastSeen {
+    pub fn now() -> Self {
+        Self(unix_secs())
+    }
+
+    fn elapsed_secs(&self) -> u64 {
+        unix_secs().saturating_sub(self.0)
+    }
+}
+impl Saveable for LastSeen {
+    fn save(&self, filename: &str) -> Result<()> {
+        format_save(self, filename)
+    }
+
+    fn load(filename: &str) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        format_load(filename)
+    }
+}
+
+fn unix_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+// WHAT ONE POWER MADE, AND WHAT ITS UPKEEP AND FINES TOOK, WHILE THE GAME WAS CLOSED
+struct OfflineLine {
+    id: String,
+    earned: BigNumber,
+    lost: BigNumber,
+}
+
+#[derive(Resource)]
+struct OfflineEarnings {
+    away_secs: u64,
+    capped: bool,
+    lines: Vec<OfflineLine>,
+    // THE BANK BEFORE AND AFTER, SO THE SUMMARY MATCHES WHAT CHANGED
+    before: BigNumber,
+    after: BigNumber,
+}
+
+// EVERYTHING THE ECONOMY NEEDS TO RUN OVER THE TIME AWAY
+#[derive(SystemParam)]
+struct OfflineInputs<'w> {
+    settings: Res<'w, Settings>,
+    prestige: Res<'w, Prestige>,
+    modifiers: Res<'w, UpgradeModifiers>,
+    synergies: Res<'w, Synergies>,
+}
+
+fn credit_offline_progress(
+    mut commands: Commands,
+    inputs: OfflineInputs,
+    mut economy: ResMut<GameEconomy>,
+    mut evw_power_earned: EventWriter<PowerEarned>,
+    mut evw_power_lost: EventWriter<PowerLost>,
+    mut evw_toast: EventWriter<Toast>,
+    mut statistics: ResMut<Statistics>,
+) {
+    // NO SAVE YET MEANS NOTHING TO CREDIT
+    let Ok(last_seen) = LastSeen::load("last_seen.ron") else {
+        return;
+    };
+
+    let away_secs = last_seen.elapsed_secs();
+    let cap_secs = (inputs.settings.offline_cap_hours.max(0.0) * 3600.0) as u64;
+    let credited_secs = away_secs.min(cap_secs);
+
+    // RUN THE ECONOMY OVER THE TIME AWAY, WORLD EVENTS NEVER HAPPEN OFFLINE
+    economy.apply_modifiers(
+        &inputs.modifiers,
+        &inputs.synergies,
+        inputs.prestige.production_multiplier(),
+    );
+    let before = economy.total_power();
+    let report = economy.step(credited_secs as f64);
+
+    let mut lines: Vec<OfflineLine> = Vec::new();
+    for payout in report.payouts.iter() {
+        evw_power_earned.send(PowerEarned {
+            amount: payout.earned,
+            source: EarnedFrom::Power(payout.id.clone()),
+        });
+        lines.push(OfflineLine {
+            id: payout.id.clone(),
+            earned: payout.earned,
+            lost: BigNumber::ZERO,
+        });
+    }
+    let lost = power_lost_events(&report);
+    for ev in lost.iter() {
+        let (LostTo::Upkeep(id) | LostTo::Fine { id, .. }) = &ev.source;
+        match lines.iter_mut().find(|line| line.id == *id) {
+            Some(line) => line.lost += ev.amount,
+            None => lines.push(OfflineLine {
+                id: id.clone(),
+                earned: BigNumber::ZERO,
+                lost: ev.amount,
+            }),
+        }
+    }
+    evw_power_lost.send_batch(lost);
+    // BUTTONS FOR THESE SPAWN WITH THE REST ON ENTERING PLAYING
+    for id in report.unlocked.iter() {
+        statistics.record_unlock(id);
+        let title = economy.power(id).map_or(id.as_str(), |power| power.title());
+        evw_toast.send(Toast(format!("UNLOCKED WHILE AWAY: {}", title)));
+        info!("[UNLOCKED] Power ID: {}", id);
+    }
+
+    info!(
+        "[LOADED] Offline Progress: {}s Away, {}s Credited",
+        away_secs, credited_secs
+    );
+
+    if !lines.is_empty() {
+        commands.insert_resource(OfflineEarnings {
+            away_secs,
+            capped: away_secs > cap_secs,
+            lines,
+            before,
+            after: economy.total_power(),
+        });
+    }
+}
+
+fn line_text(line: &OfflineLine, economy: &GameEconomy) -> String {
+    let title = economy
+        .power(&line.id)
+        .map_or(line.id.as_str(), |power| power.title());
+    if line.lost.is_zero() {
+        format!("{}: +{}", title, line.earned)
+    } else {
+        format!("{}: +{} -{}", title, line.earned, line.lost)
+    }
+}
+
+// THE CHANGE IN THE BANK, WHICH UPKEEP AND FINES CAN MAKE NEGATIVE
+fn net_text(offline_earnings: &OfflineEarnings) -> String {
+    if offline_earnings.after >= offline_earnings.before {
+        format!("NET: +{}", offline_earnings.after - offline_earnings.before)
+    } else {
+        format!("NET: -{}", offline_earnings.before - offline_earnings.after)
+    }
+}
+
+fn summary_startup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    economy: Res<GameEconomy>,
+    offline_earnings: Option<Res<OfflineEarnings>>,
+) {
+    let Some(offline_earnings) = offline_earnings else {
+        return;
+    };
+
+    let font = asset_server.load("fonts/PublicPixel.ttf");
+    let parent_style = (
+        BorderColor(Color::NONE),
+        BorderRadius::ZERO,
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+        FocusPolicy::Block,
+    );
+
+    let children_style = (
+        BorderColor(Pallette::Black.srgb()),
+        BorderRadius::all(Val::Percent(10.0)),
+        BackgroundColor(Pallette::Lighter.srgb()),
+    );
+
+    let away = format_duration(offline_earnings.away_secs);
+    let away = if offline_earnings.capped {
+        format!("AWAY FOR {away} (CAPPED)")
+    } else {
+        format!("AWAY FOR {away}")
+    };
+
+    commands
+        .spawn((
+            DialogParentNode::node(),
+            DialogParentNode::marker(),
+            parent_style,
+            CleanupOfflineSummary,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("WHILE YOU WERE AWAY"),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 40.0,
+                    ..default()
+                },
+                TextColor(Pallette::Lighter.srgb()),
+            ));
+
+            parent.spawn((
+                Text::new(away),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Pallette::Lighter.srgb()),
+            ));
+
+            for line in offline_earnings.lines.iter() {
+                parent.spawn((
+                    Text::new(line_text(line, &economy)),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 20.0,
+                        ..default()
+                    },
+                    TextColor(Pallette::White.srgb()),
+                ));
+            }
+
+            parent.spawn((
+                Text::new(net_text(&offline_earnings)),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Pallette::Lighter.srgb()),
+            ));
+
+            parent
+                .spawn((
+                    UIButtonChildNode::node(),
+                    UIButtonChildNode::marker(),
+                    Button,
+                    UIButton,
+                    OfflineDismissButton,
+                    children_style,
+                ))
+                .with_child((
+                    Text::new("OK"),
+                    TextFont {
+                        font,
+                        font_size: 33.0,
+                        ..default()
+                    },
+                    TextColor(Pallette::Black.srgb()),
+                ));
+        });
+    info!("[SPAWNED] Offline Summary Entities");
+}
+
+fn summary_cleanup(
+    mut commands: Commands,
+    query_entity: Query<Entity, With<CleanupOfflineSummary>>,
+) {
+    for entity in query_entity.iter() {
+        commands.entity(entity).despawn_recursive();
+        info!("[DESPAWNED] Offline Summary Entities");
+    }
+}
+
+fn dismiss_click(
+    mut commands: Commands,
+    query_entity: Query<Entity, With<CleanupOfflineSummary>>,
+    mut interaction_query: Query<&Interaction, (Changed<Interaction>, With<OfflineDismissButton>)>,
+) {
+    for interaction in &mut interaction_query {
+        if *interaction == Interaction::Pressed {
+            for entity in query_entity.iter() {
+                commands.entity(entity).despawn_recursive();
+            }
+            // ONLY SHOW THE SUMMARY ONCE PER SESSION
+            commands.remove_resource::<OfflineEarnings>();
+            info!("[DESPAWNED] Offline Summary Entities");
+        }
+    }
+}
+
+fn format_duration(secs: u64) -> String {
+    let (hours, minutes, seconds) = (secs / 3600, (secs % 3600) / 60, secs % 60);
+    if hours > 0 {
+        format!("{hours}h {minutes}m")
+    } else if minutes > 0 {
+        format!("{minutes}m {seconds}s")
+    } else {
+        format!("{seconds}s")
+    }
+}
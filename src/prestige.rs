@@ -0,0 +1,447 @@
+use bevy::{prelude::*, utils::hashbrown::HashMap};
+use serde::{Deserialize, Serialize};
+use std::io::Result;
+
+use crate::{
+    game::{GameEconomy, PowerEarned},
+    number::BigNumber,
+    save::{format_load, format_save, Save, Saveable},
+    ui::{Pallette, UIButton, UIButtonChildNode, UIButtonParentNode},
+    upgrades::ResetUpgrades,
+    AppState, PauseState,
+};
+
+// LIFETIME POWER EARNED FOR THE FIRST PRESTIGE POINT, EACH NEXT POINT NEEDS MORE
+const PRESTIGE_THRESHOLD: f64 = 1e10;
+// PRODUCTION BONUS FOR EVERY PRESTIGE POINT EVER EARNED
+const BONUS_PER_POINT: f64 = 0.02;
+
+pub struct PrestigePlugin;
+impl Plugin for PrestigePlugin {
+    fn name(&self) -> &str {
+        "Prestige Plugin"
+    }
+
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::Prestige), startup)
+            .add_systems(OnExit(AppState::Prestige), cleanup)
+            .add_systems(
+                Update,
+                (
+                    prestige_button_interaction,
+                    update_prestige_text,
+                    escape_to_game,
+                )
+                    .run_if(in_state(AppState::Prestige)),
+            )
+            .add_systems(Update, track_run_earned)
+            .add_systems(
+                Update,
+                track_run_time.run_if(in_state(PauseState::Unpaused)),
+            )
+            .insert_resource(Prestige::load("prestige.ron").unwrap_or_default());
+    }
+}
+
+#[derive(Component)]
+struct CleanupPrestigeMenu;
+
+#[derive(Component)]
+struct PrestigeInfoText;
+
+#[derive(Component)]
+struct RebirthText;
+
+#[derive(Component)]
+struct ShopText(PrestigeUpgrade);
+
+#[derive(Component)]
+enum PrestigeMenuButton {
+    Rebirth,
+    Buy(PrestigeUpgrade),
+    Back,
+}
+
+/// Permanent upgrades bought with prestige points. They survive every rebirth.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum PrestigeUpgrade {
+    Overclock,
+    StaticFingers,
+    HeadStart,
+}
+impl PrestigeUpgrade {
+    const ALL: [PrestigeUpgrade; 3] = [
+        PrestigeUpgrade::Overclock,
+        PrestigeUpgrade::StaticFingers,
+        PrestigeUpgrade::HeadStart,
+    ];
+
+    fn title(&self) -> &str {
+        use PrestigeUpgrade::*;
+
+        match self {
+            Overclock => "Overclock",
+            StaticFingers => "Static Fingers",
+            HeadStart => "Head Start",
+        }
+    }
+
+    fn description(&self) -> &str {
+        use PrestigeUpgrade::*;
+
+        match self {
+            Overclock => "+25% PROD",
+            StaticFingers => "x2 CLICKS",
+            HeadStart => "x10 START PWR",
+        }
+    }
+
+    fn cost(&self, level: u32) -> BigNumber {
+        use PrestigeUpgrade::*;
+
+        let (base, growth) = match self {
+            Overclock => (1.0, 2.0),
+            StaticFingers => (2.0, 3.0),
+            HeadStart => (5.0, 5.0),
+        };
+        BigNumber::powf(growth, level as f64) * base
+    }
+}
+
+#[derive(Default, Deserialize, Resource, Serialize)]
+#[serde(default)]
+pub struct Prestige {
+    // SPENDABLE IN THE SHOP
+    points: BigNumber,
+    // EVERY POINT EVER EARNED, DRIVES THE PRODUCTION BONUS
+    total_points: BigNumber,
+    rebirths: u32,
+    run_earned: BigNumber,
+    lifetime_earned: BigNumber,
+    // UNPAUSED SECONDS PLAYED THIS RUN
+    run_secs: f64,
+    upgrades: HashMap<PrestigeUpgrade, u32>,
+}
+impl Prestige {
+    pub fn level(&self, upgrade: PrestigeUpgrade) -> u32 {
+        self.upgrades.get(&upgrade).copied().unwrap_or(0)
+    }
+
+    pub fn rebirths(&self) -> u32 {
+        self.rebirths
+    }
+
+    pub fn run_earned(&self) -> BigNumber {
+        self.run_earned
+    }
+
+    pub fn lifetime_earned(&self) -> BigNumber {
+        self.lifetime_earned
+    }
+
+    pub fn run_secs(&self) -> f64 {
+        self.run_secs
+    }
+
+    /// Points a rebirth would pay out right now: what lifetime earnings are
+    /// worth, less every point already paid out.
+    pub fn pending_points(&self) -> BigNumber {
+        (self.lifetime_earned / PRESTIGE_THRESHOLD).sqrt().floor() - self.total_points
+    }
+
+    pub fn production_multiplier(&self) -> BigNumber {
+        let from_points = BigNumber::ONE + self.total_points * BONUS_PER_POINT;
+        let overclock = 1.0 + 0.25 * self.level(PrestigeUpgrade::Overclock) as f64;
+        from_points * overclock
+    }
+
+    pub fn click_multiplier(&self) -> BigNumber {
+        BigNumber::powf(2.0, self.level(PrestigeUpgrade::StaticFingers) as f64)
+    }
+
+    pub fn head_start(&self) -> BigNumber {
+        match self.level(PrestigeUpgrade::HeadStart) {
+            0 => BigNumber::ZERO,
+            level => BigNumber::powf(10.0, level as f64 + 2.0),
+        }
+    }
+
+    /// Pays out pending points and starts the run counters over. The caller
+    /// resets the economy itself.
+    pub fn rebirth(&mut self) -> BigNumber {
+        let gain = self.pending_points();
+        self.points += gain;
+        self.total_points += gain;
+        self.rebirths += 1;
+        self.run_earned = BigNumber::ZERO;
+        self.run_secs = 0.0;
+        gain
+    }
+
+    fn buy(&mut self, upgrade: PrestigeUpgrade) -> bool {
+        let cost = upgrade.cost(self.level(upgrade));
+        if self.points < cost {
+            return false;
+        }
+        self.points -= cost;
+        *self.upgrades.entry(upgrade).or_insert(0) += 1;
+        true
+    }
+}
+impl Saveable for Prestige {
+    fn save(&self, filename: &str) -> Result<()> {
+        format_save(self, filename)
+    }
+
+    fn load(filename: &str) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        format_load(filename)
+    }
+}
+
+fn track_run_earned(
+    mut evr_power_earned: EventReader<PowerEarned>,
+    mut prestige: ResMut<Prestige>,
+) {
+    for ev in evr_power_earned.read() {
+        prestige.run_earned += ev.amount;
+        prestige.lifetime_earned += ev.amount;
+    }
+}
+
+fn track_run_time(time: Res<Time>, mut prestige: ResMut<Prestige>) {
+    prestige.run_secs += time.delta_secs_f64();
+}
+
+fn startup(mut commands: Commands, asset_server: Res<AssetServer>, prestige: Res<Prestige>) {
+    let font = asset_server.load("fonts/PublicPixel.ttf");
+
+    let style = (
+        BorderColor(Pallette::Black.srgb()),
+        BorderRadius::all(Val::Percent(10.0)),
+        BackgroundColor(Pallette::Lighter.srgb()),
+    );
+
+    // SPAWN PRESTIGE INFO NODE
+    commands
+        .spawn((
+            UIButtonParentNode::new(100.0, 20.0, 0.0),
+            UIButtonParentNode::marker(),
+            CleanupPrestigeMenu,
+        ))
+        .with_child((
+            Text::new(prestige_info(&prestige)),
+            TextFont {
+                font: font.clone(),
+                font_size: 30.0,
+                ..default()
+            },
+            TextColor(Pallette::White.srgb()),
+            PrestigeInfoText,
+        ));
+
+    // SPAWN REBIRTH BUTTON NODE
+    commands
+        .spawn((
+            UIButtonParentNode::new(100.0, 20.0, 20.0),
+            UIButtonParentNode::marker(),
+            CleanupPrestigeMenu,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    UIButtonChildNode::node(),
+                    UIButtonChildNode::marker(),
+                    Button,
+                    PrestigeMenuButton::Rebirth,
+                    UIButton,
+                    style,
+                ))
+                .with_child((
+                    Text::new(rebirth_label(&prestige)),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 20.0,
+                        ..default()
+                    },
+                    TextColor(Pallette::Black.srgb()),
+                    RebirthText,
+                ));
+        });
+
+    // SPAWN SHOP NODE
+    commands
+        .spawn((
+            UIButtonParentNode::new(100.0, 20.0, 40.0),
+            UIButtonParentNode::marker(),
+            CleanupPrestigeMenu,
+        ))
+        .with_children(|parent| {
+            for upgrade in PrestigeUpgrade::ALL {
+                parent
+                    .spawn((
+                        UIButtonChildNode::node(),
+                        UIButtonChildNode::marker(),
+                        Button,
+                        PrestigeMenuButton::Buy(upgrade),
+                        UIButton,
+                        style,
+                    ))
+                    .with_child((
+                        Text::new(shop_label(&prestige, upgrade)),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(Pallette::Black.srgb()),
+                        ShopText(upgrade),
+                    ));
+            }
+        });
+
+    // SPAWN BACK BUTTON NODE
+    commands
+        .spawn((
+            UIButtonParentNode::new(100.0, 20.0, 80.0),
+            UIButtonParentNode::marker(),
+            CleanupPrestigeMenu,
+        ))
+        .with_children(|parent| {
+            parent
+                .spawn((
+                    UIButtonChildNode::node(),
+                    UIButtonChildNode::marker(),
+                    Button,
+                    PrestigeMenuButton::Back,
+                    UIButton,
+                    style,
+                ))
+                .with_child((
+                    Text::new("BACK"),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 33.0,
+                        ..default()
+                    },
+                    TextColor(Pallette::Black.srgb()),
+                ));
+        });
+
+    info!("[SPAWNED] Prestige Menu Entities.");
+}
+
+fn cleanup(mut commands: Commands, query_cleanup: Query<Entity, With<CleanupPrestigeMenu>>) {
+    for entity in query_cleanup.iter() {
+        commands.entity(entity).despawn_recursive();
+        info!("[DESPAWNED] Prestige Menu Entities");
+    }
+}
+
+fn prestige_info(prestige: &Prestige) -> String {
+    format!(
+        "PRESTIGE: {}\nREBIRTHS: {}\nPROD: x{}",
+        prestige.points,
+        prestige.rebirths,
+        prestige.production_multiplier()
+    )
+}
+
+fn rebirth_label(prestige: &Prestige) -> String {
+    format!("REBIRTH\n+{}", prestige.pending_points())
+}
+
+fn shop_label(prestige: &Prestige, upgrade: PrestigeUpgrade) -> String {
+    let level = prestige.level(upgrade);
+    format!(
+        "{}\n{}\nLV {} COST {}",
+        upgrade.title(),
+        upgrade.description(),
+        level,
+        upgrade.cost(level)
+    )
+}
+
+fn update_prestige_text(
+    prestige: Res<Prestige>,
+    mut query_info_text: Query<&mut Text, With<PrestigeInfoText>>,
+    mut query_rebirth_text: Query<&mut Text, (With<RebirthText>, Without<PrestigeInfoText>)>,
+    mut query_shop_text: Query<
+        (&ShopText, &mut Text),
+        (Without<RebirthText>, Without<PrestigeInfoText>),
+    >,
+) {
+    if prestige.is_changed() {
+        for mut text in &mut query_info_text {
+            **text = prestige_info(&prestige);
+        }
+        for mut text in &mut query_rebirth_text {
+            **text = rebirth_label(&prestige);
+        }
+        for (shop_text, mut text) in &mut query_shop_text {
+            **text = shop_label(&prestige, shop_text.0);
+        }
+    }
+}
+
+fn prestige_button_interaction(
+    mut prestige: ResMut<Prestige>,
+    mut economy: ResMut<GameEconomy>,
+    mut evw_reset_upgrades: EventWriter<ResetUpgrades>,
+    mut evw_save: EventWriter<Save>,
+    mut next_state: ResMut<NextState<AppState>>,
+    mut interaction_query: Query<
+        (&Interaction, &PrestigeMenuButton),
+        (Changed<Interaction>, With<PrestigeMenuButton>),
+    >,
+) {
+    use PrestigeMenuButton::*;
+
+    for (interaction, pmb) in &mut interaction_query {
+        if *interaction == Interaction::Pressed {
+            match pmb {
+                Rebirth => {
+                    if prestige.pending_points().is_zero() {
+                        info!("[INVALID] Not Enough Power To Prestige");
+                        continue;
+                    }
+
+                    // PAY OUT AND START THE NEXT RUN
+                    let gain = prestige.rebirth();
+
+                    // RESET THE RUN
+                    economy.reset_run(prestige.head_start());
+                    evw_reset_upgrades.send(ResetUpgrades);
+
+                    evw_save.send(Save);
+                    info!("[MODIFIED] Prestige: +{} Points", gain);
+                }
+                Buy(upgrade) => {
+                    if prestige.buy(*upgrade) {
+                        evw_save.send(Save);
+                        info!(
+                            "[MODIFIED] Prestige Upgrade: {:?} >> {}",
+                            upgrade,
+                            prestige.level(*upgrade)
+                        );
+                    } else {
+                        info!("[INVALID] Insufficient Prestige");
+                    }
+                }
+                Back => {
+                    next_state.set(AppState::Playing);
+                    info!("[MODIFIED] AppState >> Playing");
+                }
+            }
+        }
+    }
+}
+
+fn escape_to_game(keys: Res<ButtonInput<KeyCode>>, mut next_state: ResMut<NextState<AppState>>) {
+    if keys.just_pressed(KeyCode::Escape) {
+        next_state.set(AppState::Playing);
+        info!("[MODIFIED] AppState >> Playing");
+    }
+}
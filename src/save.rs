@@ -1,4 +1,5 @@
 use bevy::{
+    ecs::system::SystemParam,
     prelude::*,
     scene::ron::{
         de::from_reader,
@@ -13,9 +14,9 @@ use std::{
 };
 
 use crate::{
-    game::{PowerUnlockFlags, Powers, TotalPower},
-    settings::Settings,
-    AppState,
+    achievements::Achievements, automation::AutoBuyer, game::GameEconomy, offline::LastSeen,
+    prestige::Prestige, settings::Settings, statistics::Statistics, upgrades::PurchasedUpgrades,
+    victory::Victory, AppState,
 };
 
 pub struct SavePlugin;
@@ -107,19 +108,33 @@ where
 #[derive(Event)]
 pub struct Save;
 
-fn evr_save(
-    mut evr_save: EventReader<Save>,
-    settings: Res<Settings>,
-    power_flags: Res<PowerUnlockFlags>,
-    total_power: Res<TotalPower>,
-    powers: Res<Powers>,
-) {
+// EVERY RESOURCE THAT GOES INTO A SAVE
+#[derive(SystemParam)]
+struct SaveData<'w> {
+    settings: Res<'w, Settings>,
+    economy: Res<'w, GameEconomy>,
+    prestige: Res<'w, Prestige>,
+    purchased_upgrades: Res<'w, PurchasedUpgrades>,
+    achievements: Res<'w, Achievements>,
+    victory: Res<'w, Victory>,
+    statistics: Res<'w, Statistics>,
+    auto_buyer: Res<'w, AutoBuyer>,
+}
+
+fn evr_save(mut evr_save: EventReader<Save>, data: SaveData) {
     for _ev in evr_save.read() {
         info!("[EVENT] [READ] Save Game");
-        let _ = settings.save("settings.ron");
-        let _ = power_flags.save("power_unlocks.ron");
-        let _ = total_power.save("total_power.ron");
-        let _ = powers.save("powers.ron");
+        let _ = data.settings.save("settings.ron");
+        let _ = data.economy.unlock_flags().save("power_unlocks.ron");
+        let _ = data.achievements.save("achievements.ron");
+        let _ = data.economy.total().save("total_power.ron");
+        let _ = data.economy.states().save("power_states.ron");
+        let _ = data.prestige.save("prestige.ron");
+        let _ = data.purchased_upgrades.save("upgrades.ron");
+        let _ = data.victory.save("victory.ron");
+        let _ = data.statistics.save("statistics.ron");
+        let _ = data.auto_buyer.save("auto_buyer.ron");
+        let _ = LastSeen::now().save("last_seen.ron");
     }
 }
 
@@ -29,11 +29,26 @@ impl Plugin for SettingsPlugin {
     }
 }
 
-#[derive(Default, Deserialize, Serialize, Resource)]
+#[derive(Deserialize, Serialize, Resource)]
+#[serde(default)]
 pub struct Settings {
     resolution: Resolution,
     monitor: usize,
     pub auto_click: bool,
+    pub offline_cap_hours: f64,
+    // FIXED SEED FOR GAMEPLAY RNG, NONE PICKS A FRESH ONE EVERY LAUNCH
+    pub rng_seed: Option<u64>,
+}
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            resolution: Resolution::default(),
+            monitor: 0,
+            auto_click: false,
+            offline_cap_hours: 8.0,
+            rng_seed: None,
+        }
+    }
 }
 impl Settings {
     pub fn set_resolution(&mut self, resolution: Resolution) {
@@ -0,0 +1,327 @@
+use bevy::{prelude::*, ui::FocusPolicy, utils::hashbrown::HashMap};
+use serde::{Deserialize, Serialize};
+use std::io::Result;
+
+use crate::{
+    achievements::Achievements,
+    game::{EarnedFrom, GameEconomy, LostTo, PowerEarned, PowerLost},
+    number::{clock, BigNumber},
+    save::{format_load, format_save, Saveable},
+    ui::{
+        Pallette, PauseMenuButton, StatisticsListNode, UIButton, UIButtonChildNode,
+        UIButtonParentNode,
+    },
+    AppState, PauseState,
+};
+
+pub struct StatisticsPlugin;
+impl Plugin for StatisticsPlugin {
+    fn name(&self) -> &str {
+        "Statistics Plugin"
+    }
+
+    fn build(&self, app: &mut App) {
+        // SAVES FROM BEFORE STATISTICS KEEP THEIR CLICKS FROM ACHIEVEMENTS
+        let mut statistics = Statistics::load("statistics.ron").unwrap_or_else(|_| Statistics {
+            clicks: Achievements::load("achievements.ron")
+                .map_or(0, |achievements| achievements.legacy_clicks()),
+            ..default()
+        });
+        // EVERY LAUNCH IS A NEW SESSION
+        statistics.sessions += 1;
+
+        app.add_systems(OnEnter(AppState::Statistics), startup)
+            .add_systems(OnExit(AppState::Statistics), cleanup)
+            .add_systems(Update, back_to_menu.run_if(in_state(AppState::Statistics)))
+            // FROM THE PAUSE MENU IT OPENS OVER THE GAME, WHICH STAYS SPAWNED UNDERNEATH
+            .add_systems(OnEnter(PauseState::Statistics), overlay_startup)
+            .add_systems(OnExit(PauseState::Statistics), cleanup)
+            .add_systems(
+                Update,
+                back_to_pause.run_if(in_state(PauseState::Statistics)),
+            )
+            .add_systems(Update, (track_earned, track_lost))
+            .add_systems(
+                Update,
+                track_time_played.run_if(in_state(PauseState::Unpaused)),
+            )
+            .insert_resource(statistics);
+    }
+}
+
+#[derive(Component)]
+struct CleanupStatisticsMenu;
+
+#[derive(Component)]
+enum StatisticsMenuButton {
+    Back,
+}
+
+/// Lifetime record of how every run went. Never reset by rebirths.
+#[derive(Default, Deserialize, Resource, Serialize)]
+#[serde(default)]
+pub struct Statistics {
+    clicks: u64,
+    auto_clicks: u64,
+    earned: BigNumber,
+    spent: BigNumber,
+    // EVERYTHING EACH POWER EVER PAID OUT, BY ID
+    produced: HashMap<String, BigNumber>,
+    purchases: u64,
+    // TAKEN BY POWER TRAITS, NEVER COUNTED AS SPENT
+    upkeep: BigNumber,
+    fines: BigNumber,
+    failures: u64,
+    played_secs: f64,
+    sessions: u32,
+    // TIME PLAYED WHEN EACH POWER FIRST UNLOCKED, BY ID
+    unlocked_at: HashMap<String, f64>,
+}
+impl Statistics {
+    pub fn record_purchase(&mut self, price: BigNumber) {
+        self.purchases += 1;
+        self.spent += price;
+    }
+
+    pub fn clicks(&self) -> u64 {
+        self.clicks
+    }
+
+    pub fn played_secs(&self) -> f64 {
+        self.played_secs
+    }
+
+    /// Only the first unlock of each power is kept.
+    pub fn record_unlock(&mut self, id: &str) {
+        if !self.unlocked_at.contains_key(id) {
+            self.unlocked_at.insert(id.to_string(), self.played_secs);
+        }
+    }
+}
+impl Saveable for Statistics {
+    fn save(&self, filename: &str) -> Result<()> {
+        format_save(self, filename)
+    }
+
+    fn load(filename: &str) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        format_load(filename)
+    }
+}
+
+fn track_earned(
+    mut evr_power_earned: EventReader<PowerEarned>,
+    mut statistics: ResMut<Statistics>,
+) {
+    for ev in evr_power_earned.read() {
+        statistics.earned += ev.amount;
+        match &ev.source {
+            EarnedFrom::Power(id) => {
+                *statistics
+                    .produced
+                    .entry(id.clone())
+                    .or_insert(BigNumber::ZERO) += ev.amount;
+            }
+            EarnedFrom::Click => statistics.clicks += 1,
+            EarnedFrom::AutoClick => statistics.auto_clicks += 1,
+            EarnedFrom::Reward => {}
+        }
+    }
+}
+
+fn track_lost(mut evr_power_lost: EventReader<PowerLost>, mut statistics: ResMut<Statistics>) {
+    for ev in evr_power_lost.read() {
+        match &ev.source {
+            LostTo::Upkeep(_) => statistics.upkeep += ev.amount,
+            LostTo::Fine { failures, .. } => {
+                statistics.fines += ev.amount;
+                statistics.failures += failures;
+            }
+        }
+    }
+}
+
+fn track_time_played(time: Res<Time>, mut statistics: ResMut<Statistics>) {
+    statistics.played_secs += time.delta_secs_f64();
+}
+
+fn startup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    statistics: Res<Statistics>,
+    economy: Res<GameEconomy>,
+) {
+    spawn_statistics(&mut commands, &asset_server, &statistics, &economy, false);
+    info!("[SPAWNED] Statistics Menu Entities.");
+}
+
+fn overlay_startup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    statistics: Res<Statistics>,
+    economy: Res<GameEconomy>,
+) {
+    spawn_statistics(&mut commands, &asset_server, &statistics, &economy, true);
+    info!("[SPAWNED] Statistics Overlay Entities.");
+}
+
+// THE OVERLAY DARKENS AND BLOCKS THE GAME BEHIND IT, AND ITS BACK BUTTON IS LIT LIKE THE PAUSE MENU'S
+fn spawn_statistics(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    statistics: &Statistics,
+    economy: &GameEconomy,
+    overlay: bool,
+) {
+    let font = asset_server.load("fonts/PublicPixel.ttf");
+
+    let style = (
+        BorderColor(Pallette::Black.srgb()),
+        BorderRadius::all(Val::Percent(10.0)),
+        BackgroundColor(Pallette::Lighter.srgb()),
+    );
+
+    // SPAWN STATISTICS LIST
+    let mut list = commands.spawn((
+        StatisticsListNode::node(),
+        StatisticsListNode::marker(),
+        CleanupStatisticsMenu,
+    ));
+    if overlay {
+        list.insert((
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.85)),
+            FocusPolicy::Block,
+        ));
+    }
+    list
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("STATISTICS"),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 30.0,
+                    ..default()
+                },
+                TextColor(Pallette::Lighter.srgb()),
+            ));
+
+            parent.spawn((
+                Text::new(format!(
+                    "TIME PLAYED: {}\nSESSIONS: {}\nCLICKS: {}\nAUTO CLICKS: {}\nEARNED: {}\nSPENT: {}\nPURCHASES: {}\nFUEL BURNED: {}\nFINES: {} ({} FAILURES)",
+                    clock(statistics.played_secs),
+                    statistics.sessions,
+                    statistics.clicks,
+                    statistics.auto_clicks,
+                    statistics.earned,
+                    statistics.spent,
+                    statistics.purchases,
+                    statistics.upkeep,
+                    statistics.fines,
+                    statistics.failures
+                )),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Pallette::White.srgb()),
+            ));
+
+            // ONE LINE PER POWER THAT HAS EVER UNLOCKED
+            for power in economy.powers() {
+                let Some(unlocked_at) = statistics.unlocked_at.get(power.id()) else {
+                    continue;
+                };
+                let produced = statistics
+                    .produced
+                    .get(power.id())
+                    .copied()
+                    .unwrap_or(BigNumber::ZERO);
+                parent.spawn((
+                    Text::new(format!(
+                        "{}: {} PRODUCED, UNLOCKED AT {}",
+                        power.title(),
+                        produced,
+                        clock(*unlocked_at)
+                    )),
+                    TextFont {
+                        font: font.clone(),
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(Pallette::Lighter.srgb()),
+                ));
+            }
+        });
+
+    // SPAWN BACK BUTTON NODE
+    commands
+        .spawn((
+            UIButtonParentNode::new(100.0, 20.0, 80.0),
+            UIButtonParentNode::marker(),
+            CleanupStatisticsMenu,
+        ))
+        .with_children(|parent| {
+            let mut back = parent.spawn((
+                UIButtonChildNode::node(),
+                UIButtonChildNode::marker(),
+                Button,
+                StatisticsMenuButton::Back,
+                UIButton,
+                style,
+            ));
+            if overlay {
+                back.insert(PauseMenuButton);
+            }
+            back.with_child((
+                Text::new("BACK"),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 33.0,
+                    ..default()
+                },
+                TextColor(Pallette::Black.srgb()),
+            ));
+        });
+}
+
+fn cleanup(mut commands: Commands, query_cleanup: Query<Entity, With<CleanupStatisticsMenu>>) {
+    for entity in query_cleanup.iter() {
+        commands.entity(entity).despawn_recursive();
+        info!("[DESPAWNED] Statistics Menu Entities");
+    }
+}
+
+fn back_pressed(
+    keys: &ButtonInput<KeyCode>,
+    interaction_query: &Query<(&Interaction, &StatisticsMenuButton), Changed<Interaction>>,
+) -> bool {
+    keys.just_pressed(KeyCode::Escape)
+        || interaction_query.iter().any(|(interaction, smb)| {
+            *interaction == Interaction::Pressed && matches!(smb, StatisticsMenuButton::Back)
+        })
+}
+
+fn back_to_menu(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut next_state: ResMut<NextState<AppState>>,
+    interaction_query: Query<(&Interaction, &StatisticsMenuButton), Changed<Interaction>>,
+) {
+    if back_pressed(&keys, &interaction_query) {
+        next_state.set(AppState::Menu);
+        info!("[MODIFIED] AppState >> Menu");
+    }
+}
+
+fn back_to_pause(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut next_state: ResMut<NextState<PauseState>>,
+    interaction_query: Query<(&Interaction, &StatisticsMenuButton), Changed<Interaction>>,
+) {
+    if back_pressed(&keys, &interaction_query) {
+        next_state.set(PauseState::Paused);
+        info!("[MODIFIED] PauseState >> Paused");
+    }
+}
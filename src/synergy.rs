@@ -0,0 +1,83 @@
+use bevy::{
+    prelude::*,
+    scene::ron::de::from_str,
+    utils::hashbrown::{HashMap, HashSet},
+};
+use serde::Deserialize;
+
+use crate::{
+    game::{load_power_definitions, GameEconomy, PowerDefinitionsReloaded},
+    AppState,
+};
+
+pub struct SynergyPlugin;
+impl Plugin for SynergyPlugin {
+    fn name(&self) -> &str {
+        "Synergy Plugin"
+    }
+
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            OnExit(AppState::Loading),
+            validate_synergies.after(load_power_definitions),
+        )
+        .add_systems(
+            Update,
+            validate_synergies.run_if(on_event::<PowerDefinitionsReloaded>),
+        )
+        .insert_resource(Synergies::from_ron(include_str!(
+            "../assets/data/synergies.ron"
+        )));
+    }
+}
+
+#[derive(Deserialize)]
+struct Synergy {
+    source: String,
+    target: String,
+    bonus: f64,
+}
+
+/// Every synergy rule defined in `assets/data/synergies.ron`.
+#[derive(Default, Resource)]
+pub struct Synergies(Vec<Synergy>);
+impl Synergies {
+    pub fn from_ron(s: &str) -> Self {
+        match from_str::<Vec<Synergy>>(s) {
+            Ok(synergies) => {
+                info!("[LOADED] Synergies: {}", synergies.len());
+                Self(synergies)
+            }
+            Err(e) => {
+                info!("[ERROR] Synergies Failed To Parse: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Production multiplier `target` gets from the copies owned of every other power.
+    pub fn multiplier(&self, target: &str, owned: &HashMap<String, i64>) -> f64 {
+        let bonus: f64 = self
+            .0
+            .iter()
+            .filter(|synergy| synergy.target == target)
+            .map(|synergy| synergy.bonus * owned.get(&synergy.source).copied().unwrap_or(0) as f64)
+            .sum();
+        (1.0 + bonus).max(0.0)
+    }
+}
+
+// DROP ANY RULE THAT POINTS AT A POWER THAT DOES NOT EXIST, AGAIN AFTER EVERY HOT RELOAD
+fn validate_synergies(mut synergies: ResMut<Synergies>, economy: Res<GameEconomy>) {
+    let ids: HashSet<&str> = economy.powers().iter().map(|power| power.id()).collect();
+    synergies.0.retain(|synergy| {
+        let valid = ids.contains(synergy.source.as_str()) && ids.contains(synergy.target.as_str());
+        if !valid {
+            info!(
+                "[ERROR] Synergy {} >> {} References An Unknown Power",
+                synergy.source, synergy.target
+            );
+        }
+        valid
+    });
+}
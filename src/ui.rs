@@ -2,6 +2,9 @@ use bevy::prelude::*;
 
 use crate::PauseState;
 
+// HOW LONG A TOAST STAYS ON SCREEN
+const TOAST_SECS: f32 = 4.0;
+
 pub struct UIPlugin;
 impl Plugin for UIPlugin {
     fn name(&self) -> &str {
@@ -9,19 +12,32 @@ impl Plugin for UIPlugin {
     }
 
     fn build(&self, app: &mut App) {
+        app.add_event::<Toast>()
+            .add_systems(Startup, spawn_toast_parent)
+            .add_systems(Update, (evr_toast, tick_toasts));
         app.add_systems(
             Update,
-            (button_color_system_unpaused, button_atlas_system_unpaused)
-                .run_if(not(in_state(PauseState::Paused))),
+            (button_color_system_unpaused, button_atlas_system_unpaused).run_if(not(paused)),
         )
         .add_systems(
             Update,
-            (button_color_system_paused, button_atlas_system_paused)
-                .run_if(in_state(PauseState::Paused)),
+            (button_color_system_paused, button_atlas_system_paused).run_if(paused),
         );
     }
 }
 
+// THE PAUSE MENU AND THE STATISTICS OVERLAY OPENED FROM IT
+fn paused(pause_state: Option<Res<State<PauseState>>>) -> bool {
+    pause_state.is_some_and(|state| *state.get() != PauseState::Unpaused)
+}
+
+/// Short message shown at the top of the screen for a few seconds.
+#[derive(Event)]
+pub struct Toast(pub String);
+
+#[derive(Component)]
+struct ToastTimer(Timer);
+
 #[derive(Component)]
 pub struct UIButton;
 
@@ -37,6 +53,25 @@ pub struct PowerButton;
 #[derive(Component)]
 pub struct SaveExitButton;
 
+#[derive(Component)]
+pub struct BuyAmountButton;
+
+#[derive(Component)]
+pub struct OfflineDismissButton;
+
+#[derive(Component)]
+pub struct PrestigeButton;
+
+#[derive(Component)]
+pub struct StatisticsButton;
+
+#[derive(Component)]
+pub struct AutomationButton;
+
+// ANY BUTTON THAT LIVES ON THE PAUSE OVERLAY
+#[derive(Component)]
+pub struct PauseMenuButton;
+
 #[derive(Component)]
 pub struct PauseParentNode;
 impl PauseParentNode {
@@ -57,6 +92,28 @@ impl PauseParentNode {
     }
 }
 
+#[derive(Component)]
+pub struct DialogParentNode;
+impl DialogParentNode {
+    pub fn node() -> Node {
+        Node {
+            width: Val::Percent(60.0),
+            height: Val::Percent(70.0),
+            top: Val::Percent(15.0),
+            left: Val::Percent(20.0),
+            row_gap: Val::Px(20.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            align_content: AlignContent::Center,
+            justify_content: JustifyContent::Center,
+            ..default()
+        }
+    }
+    pub fn marker() -> Self {
+        Self
+    }
+}
+
 #[derive(Component)]
 pub struct PauseChildNode;
 #[allow(dead_code)] //TODO:
@@ -116,6 +173,275 @@ impl PauseButtonChildNode {
     }
 }
 
+#[derive(Component)]
+pub struct BuyAmountButtonNode;
+impl BuyAmountButtonNode {
+    pub fn node() -> Node {
+        Node {
+            width: Val::Px(200.0),
+            height: Val::Px(60.0),
+            top: Val::Percent(3.0),
+            left: Val::Percent(2.0),
+            border: UiRect::all(Val::Px(5.0)),
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            ..default()
+        }
+    }
+    pub fn marker() -> Self {
+        Self
+    }
+}
+
+#[derive(Component)]
+pub struct UpgradeListNode;
+impl UpgradeListNode {
+    pub fn node() -> Node {
+        Node {
+            width: Val::Percent(9.0),
+            height: Val::Percent(60.0),
+            top: Val::Percent(12.0),
+            left: Val::Percent(90.5),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(8.0),
+            ..default()
+        }
+    }
+    pub fn marker() -> Self {
+        Self
+    }
+}
+
+#[derive(Component)]
+pub struct UpgradeButtonNode;
+impl UpgradeButtonNode {
+    pub fn node() -> Node {
+        Node {
+            width: Val::Percent(100.0),
+            padding: UiRect::all(Val::Px(6.0)),
+            border: UiRect::all(Val::Px(3.0)),
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            ..default()
+        }
+    }
+    pub fn marker() -> Self {
+        Self
+    }
+}
+
+#[derive(Component)]
+pub struct AchievementListNode;
+impl AchievementListNode {
+    pub fn node() -> Node {
+        Node {
+            width: Val::Percent(80.0),
+            height: Val::Percent(75.0),
+            top: Val::Percent(5.0),
+            left: Val::Percent(10.0),
+            row_gap: Val::Px(10.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            ..default()
+        }
+    }
+    pub fn marker() -> Self {
+        Self
+    }
+}
+
+#[derive(Component)]
+pub struct StatisticsListNode;
+impl StatisticsListNode {
+    pub fn node() -> Node {
+        Node {
+            width: Val::Percent(80.0),
+            height: Val::Percent(75.0),
+            top: Val::Percent(5.0),
+            left: Val::Percent(10.0),
+            row_gap: Val::Px(12.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            ..default()
+        }
+    }
+    pub fn marker() -> Self {
+        Self
+    }
+}
+
+#[derive(Component)]
+pub struct AutomationLogNode;
+impl AutomationLogNode {
+    pub fn node() -> Node {
+        Node {
+            width: Val::Percent(80.0),
+            height: Val::Percent(35.0),
+            top: Val::Percent(42.0),
+            left: Val::Percent(10.0),
+            position_type: PositionType::Absolute,
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Start,
+            overflow: Overflow::clip(),
+            ..default()
+        }
+    }
+    pub fn marker() -> Self {
+        Self
+    }
+}
+
+#[derive(Component)]
+pub struct CreditsRollNode;
+impl CreditsRollNode {
+    pub fn node() -> Node {
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            top: Val::Percent(100.0),
+            row_gap: Val::Px(16.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            ..default()
+        }
+    }
+    pub fn marker() -> Self {
+        Self
+    }
+}
+
+#[derive(Component)]
+pub struct ToastParentNode;
+impl ToastParentNode {
+    pub fn node() -> Node {
+        Node {
+            width: Val::Percent(30.0),
+            top: Val::Percent(2.0),
+            left: Val::Percent(35.0),
+            row_gap: Val::Px(10.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            ..default()
+        }
+    }
+    pub fn marker() -> Self {
+        Self
+    }
+}
+
+#[derive(Component)]
+pub struct ToastNode;
+impl ToastNode {
+    pub fn node() -> Node {
+        Node {
+            width: Val::Percent(100.0),
+            padding: UiRect::all(Val::Px(12.0)),
+            border: UiRect::all(Val::Px(4.0)),
+            justify_content: JustifyContent::Center,
+            ..default()
+        }
+    }
+    pub fn marker() -> Self {
+        Self
+    }
+}
+
+#[derive(Component)]
+pub struct ClickTextNode;
+impl ClickTextNode {
+    pub fn node() -> Node {
+        Node {
+            width: Val::Percent(80.0),
+            top: Val::Percent(14.0),
+            left: Val::Percent(10.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        }
+    }
+    pub fn marker() -> Self {
+        Self
+    }
+}
+
+#[derive(Component)]
+pub struct ComboNode;
+impl ComboNode {
+    pub fn node() -> Node {
+        Node {
+            width: Val::Percent(20.0),
+            top: Val::Percent(18.0),
+            left: Val::Percent(40.0),
+            row_gap: Val::Px(4.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            ..default()
+        }
+    }
+    pub fn marker() -> Self {
+        Self
+    }
+}
+
+#[derive(Component)]
+pub struct ComboBarNode;
+impl ComboBarNode {
+    pub fn node() -> Node {
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Px(14.0),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        }
+    }
+    pub fn marker() -> Self {
+        Self
+    }
+}
+
+#[derive(Component)]
+pub struct RestoreButtonNode;
+impl RestoreButtonNode {
+    pub fn node() -> Node {
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(20.0),
+            height: Val::Percent(8.0),
+            top: Val::Percent(24.0),
+            left: Val::Percent(40.0),
+            border: UiRect::all(Val::Px(5.0)),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        }
+    }
+    pub fn marker() -> Self {
+        Self
+    }
+}
+
+#[derive(Component)]
+pub struct SparkNode;
+impl SparkNode {
+    // POSITION IN PERCENT OF THE WINDOW
+    pub fn node(position: Vec2) -> Node {
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Px(40.0),
+            height: Val::Px(40.0),
+            left: Val::Percent(position.x),
+            top: Val::Percent(position.y),
+            border: UiRect::all(Val::Px(4.0)),
+            ..default()
+        }
+    }
+    pub fn marker() -> Self {
+        Self
+    }
+}
+
 #[derive(Component)]
 pub struct PowerTextNode;
 impl PowerTextNode {
@@ -261,6 +587,58 @@ impl UiButtonInfoNode {
     }
 }
 
+// HOLDS A POWER BUTTON WITH ITS PROGRESS BAR UNDERNEATH
+#[derive(Component)]
+pub struct UIButtonPowerSlotNode;
+impl UIButtonPowerSlotNode {
+    pub fn node() -> Node {
+        Node {
+            width: Val::Px(160.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(4.0),
+            ..default()
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct PowerProgressNode;
+impl PowerProgressNode {
+    pub fn node() -> Node {
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Px(12.0),
+            border: UiRect::all(Val::Px(1.0)),
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::Center,
+            ..default()
+        }
+    }
+
+    pub fn marker() -> Self {
+        Self
+    }
+}
+
+#[derive(Component)]
+pub struct PowerProgressFillNode;
+impl PowerProgressFillNode {
+    pub fn node() -> Node {
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(0.0),
+            width: Val::Percent(0.0),
+            height: Val::Percent(100.0),
+            ..default()
+        }
+    }
+
+    pub fn marker() -> Self {
+        Self
+    }
+}
+
 #[allow(dead_code)] //TODO:
 #[derive(Resource)]
 pub enum Pallette {
@@ -333,7 +711,7 @@ fn button_color_system_paused(
         ),
         (
             Changed<Interaction>,
-            With<SaveExitButton>,
+            With<PauseMenuButton>,
             Without<UIButtonPowerNode>,
         ),
     >,
@@ -414,3 +792,58 @@ fn button_atlas_system_paused(
         }
     }
 }
+
+fn spawn_toast_parent(mut commands: Commands) {
+    // TOASTS LIVE ACROSS EVERY SCREEN, SO DRAW THEM ABOVE ALL OF THEM
+    commands.spawn((
+        ToastParentNode::node(),
+        ToastParentNode::marker(),
+        GlobalZIndex(100),
+    ));
+}
+
+fn evr_toast(
+    mut commands: Commands,
+    mut evr_toast: EventReader<Toast>,
+    asset_server: Res<AssetServer>,
+    query_parent: Query<Entity, With<ToastParentNode>>,
+) {
+    let Ok(parent) = query_parent.get_single() else {
+        return;
+    };
+    for ev in evr_toast.read() {
+        commands.entity(parent).with_children(|parent| {
+            parent
+                .spawn((
+                    ToastNode::node(),
+                    ToastNode::marker(),
+                    ToastTimer(Timer::from_seconds(TOAST_SECS, TimerMode::Once)),
+                    BorderColor(Pallette::Black.srgb()),
+                    BorderRadius::all(Val::Px(10.0)),
+                    BackgroundColor(Pallette::Darker.srgb()),
+                ))
+                .with_child((
+                    Text::new(ev.0.clone()),
+                    TextFont {
+                        font: asset_server.load("fonts/PublicPixel.ttf"),
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(Pallette::Lighter.srgb()),
+                ));
+        });
+        info!("[SPAWNED] Toast: {}", ev.0);
+    }
+}
+
+fn tick_toasts(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query_toast: Query<(Entity, &mut ToastTimer)>,
+) {
+    for (entity, mut timer) in query_toast.iter_mut() {
+        if timer.0.tick(time.delta()).finished() {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
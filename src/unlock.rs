@@ -0,0 +1,146 @@
+//! When powers, upgrades and achievements unlock, as a small condition language
+//! read from `*.powers.ron`, `upgrades.ron`, `achievements.ron` and `victory.ron`.
+//!
+//! ```ron
+//! unlock: All([
+//!     Earned(10000),
+//!     Any([Owned(power: "solar_panel", amount: 10), Achievement("first_rebirth")]),
+//! ]),
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+use crate::number::BigNumber;
+
+/// A condition something locked waits on. Unlocks stick once met.
+#[derive(Clone, Default, Deserialize, Serialize)]
+pub enum UnlockCondition {
+    // NEVER UNLOCKS ON ITS OWN
+    #[default]
+    Never,
+    Always,
+    // POWER EARNED THIS RUN, SPENDING DOES NOT LOWER IT
+    Earned(BigNumber),
+    // POWER EARNED ACROSS EVERY RUN
+    LifetimeEarned(BigNumber),
+    // POWER IN THE BANK AT ONCE
+    Held(BigNumber),
+    // MANUAL SCREEN CLICKS, EVER
+    Clicks(u64),
+    // UPGRADES BOUGHT THIS RUN
+    Upgrades(usize),
+    Owned {
+        power: String,
+        amount: i64,
+    },
+    Achievement(String),
+    Rebirths(u32),
+    All(Vec<UnlockCondition>),
+    Any(Vec<UnlockCondition>),
+}
+
+/// Progress from outside the economy that conditions can test. The game feeds
+/// it from prestige, achievements, statistics and upgrades, tools fill in what they simulate.
+#[derive(Clone, Default)]
+pub struct UnlockInputs {
+    pub earned: BigNumber,
+    pub lifetime_earned: BigNumber,
+    pub rebirths: u32,
+    pub achievements: HashSet<String>,
+    pub clicks: u64,
+    pub upgrades: usize,
+}
+
+/// Everything a condition is evaluated against.
+pub struct UnlockContext<'a> {
+    pub inputs: &'a UnlockInputs,
+    pub owned: &'a HashMap<String, i64>,
+    pub held: BigNumber,
+}
+impl UnlockContext<'_> {
+    fn owned(&self, id: &str) -> i64 {
+        self.owned.get(id).copied().unwrap_or(0)
+    }
+}
+
+impl UnlockCondition {
+    pub fn is_met(&self, context: &UnlockContext) -> bool {
+        use UnlockCondition::*;
+        match self {
+            Never => false,
+            Always => true,
+            Earned(amount) => context.inputs.earned >= *amount,
+            LifetimeEarned(amount) => context.inputs.lifetime_earned >= *amount,
+            Held(amount) => context.held >= *amount,
+            Clicks(count) => context.inputs.clicks >= *count,
+            Upgrades(count) => context.inputs.upgrades >= *count,
+            Owned { power, amount } => context.owned(power) >= *amount,
+            Achievement(id) => context.inputs.achievements.contains(id),
+            Rebirths(count) => context.inputs.rebirths >= *count,
+            All(conditions) => conditions.iter().all(|c| c.is_met(context)),
+            Any(conditions) => conditions.iter().any(|c| c.is_met(context)),
+        }
+    }
+
+    /// How close the condition is to being met, from 0.0 to 1.0. `All` averages
+    /// its parts and `Any` follows whichever part is furthest along.
+    pub fn progress(&self, context: &UnlockContext) -> f64 {
+        use UnlockCondition::*;
+        if self.is_met(context) {
+            return 1.0;
+        }
+        let fraction = match self {
+            Never | Always | Achievement(_) => 0.0,
+            Earned(amount) => ratio(context.inputs.earned, *amount),
+            LifetimeEarned(amount) => ratio(context.inputs.lifetime_earned, *amount),
+            Held(amount) => ratio(context.held, *amount),
+            Clicks(count) => context.inputs.clicks as f64 / *count as f64,
+            Upgrades(count) => context.inputs.upgrades as f64 / *count as f64,
+            Owned { power, amount } => context.owned(power) as f64 / *amount as f64,
+            Rebirths(count) => context.inputs.rebirths as f64 / *count as f64,
+            All(conditions) => {
+                conditions.iter().map(|c| c.progress(context)).sum::<f64>()
+                    / conditions.len() as f64
+            }
+            Any(conditions) => conditions
+                .iter()
+                .map(|c| c.progress(context))
+                .fold(0.0, f64::max),
+        };
+        // AN UNMET CONDITION NEVER SHOWS AS DONE
+        fraction.clamp(0.0, 0.99)
+    }
+
+    /// Every power id the condition reads, so loaders can check they exist.
+    pub fn powers(&self) -> Vec<&str> {
+        use UnlockCondition::*;
+        match self {
+            Owned { power, .. } => vec![power.as_str()],
+            All(conditions) | Any(conditions) => conditions
+                .iter()
+                .flat_map(UnlockCondition::powers)
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    /// False when nothing the player does can ever meet this condition.
+    pub fn is_reachable(&self) -> bool {
+        use UnlockCondition::*;
+        match self {
+            Never => false,
+            All(conditions) => conditions.iter().all(UnlockCondition::is_reachable),
+            Any(conditions) => conditions.iter().any(UnlockCondition::is_reachable),
+            _ => true,
+        }
+    }
+}
+
+fn ratio(have: BigNumber, need: BigNumber) -> f64 {
+    if need.is_zero() {
+        1.0
+    } else {
+        (have / need).to_f64()
+    }
+}
@@ -0,0 +1,364 @@
+use bevy::{
+    prelude::*,
+    scene::ron::de::from_str,
+    utils::hashbrown::{HashMap, HashSet},
+};
+use serde::{Deserialize, Serialize};
+use std::io::Result;
+
+use crate::{
+    game::{load_power_definitions, GameEconomy, PowerDefinitionsReloaded},
+    number::BigNumber,
+    save::{format_load, format_save, Save, Saveable},
+    statistics::Statistics,
+    ui::{Pallette, UIButton, UpgradeButtonNode, UpgradeListNode},
+    unlock::UnlockCondition,
+    AppState, PauseState,
+};
+
+pub struct UpgradePlugin;
+impl Plugin for UpgradePlugin {
+    fn name(&self) -> &str {
+        "Upgrade Plugin"
+    }
+
+    fn build(&self, app: &mut App) {
+        let upgrades = Upgrades::from_ron(include_str!("../assets/data/upgrades.ron"));
+        let purchased = PurchasedUpgrades::load("upgrades.ron").unwrap_or_default();
+        let modifiers = UpgradeModifiers::new(&upgrades, &purchased);
+
+        app.add_event::<ResetUpgrades>()
+            .add_systems(OnEnter(AppState::Playing), startup)
+            .add_systems(OnExit(AppState::Playing), cleanup)
+            .add_systems(
+                Update,
+                (refresh_upgrade_list, upgrade_click).run_if(in_state(PauseState::Unpaused)),
+            )
+            .add_systems(Update, (reset_upgrades, update_modifiers).chain())
+            .add_systems(
+                OnExit(AppState::Loading),
+                validate_upgrades.after(load_power_definitions),
+            )
+            .add_systems(
+                Update,
+                validate_upgrades.run_if(on_event::<PowerDefinitionsReloaded>),
+            )
+            .insert_resource(upgrades)
+            .insert_resource(purchased)
+            .insert_resource(modifiers);
+    }
+}
+
+#[derive(Component)]
+struct CleanupUpgrades;
+
+/// Clears the upgrades bought this run, keeping permanent ones.
+#[derive(Event)]
+pub struct ResetUpgrades;
+
+#[derive(Component)]
+struct UpgradeButton(String);
+
+// IDS CURRENTLY SHOWN IN THE LIST, SO IT ONLY REBUILDS WHEN THEY CHANGE
+#[derive(Component, Default)]
+struct UpgradeList(Vec<String>);
+
+#[derive(Deserialize)]
+enum UpgradeEffect {
+    Production { power: String, multiplier: f64 },
+    Rate { power: String, multiplier: f64 },
+    Click { multiplier: f64 },
+    // EVERY CLICK ALSO PAYS THIS PERCENT OF CURRENT PWR/S
+    ClickPerSecond { percent: f64 },
+    // UNLOCKS THE AUTO-BUYER AND ITS RULES SCREEN
+    AutoBuyer,
+}
+impl UpgradeEffect {
+    fn description(&self, economy: &GameEconomy) -> String {
+        use UpgradeEffect::*;
+
+        let title = |id: &str| {
+            economy
+                .power(id)
+                .map_or("???", |power| power.title())
+                .to_string()
+        };
+
+        match self {
+            Production { power, multiplier } => format!("x{} {}", multiplier, title(power)),
+            Rate { power, multiplier } => {
+                format!("-{:.0}% TIME {}", (1.0 - multiplier) * 100.0, title(power))
+            }
+            Click { multiplier } => format!("x{} CLICK", multiplier),
+            ClickPerSecond { percent } => format!("+{}% PWR/S CLICK", percent),
+            AutoBuyer => "AUTO-BUYER".to_string(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Upgrade {
+    id: String,
+    title: String,
+    cost: BigNumber,
+    unlock: UnlockCondition,
+    effect: UpgradeEffect,
+    // KEPT THROUGH REBIRTHS AND NEW GAME+
+    #[serde(default)]
+    permanent: bool,
+}
+
+/// Every upgrade defined in `assets/data/upgrades.ron`.
+#[derive(Default, Resource)]
+struct Upgrades(Vec<Upgrade>);
+impl Upgrades {
+    fn from_ron(s: &str) -> Self {
+        match from_str::<Vec<Upgrade>>(s) {
+            Ok(upgrades) => {
+                info!("[LOADED] Upgrades: {}", upgrades.len());
+                Self(upgrades)
+            }
+            Err(e) => {
+                info!("[ERROR] Upgrades Failed To Parse: {}", e);
+                Self::default()
+            }
+        }
+    }
+}
+
+/// IDs of the upgrades bought this run.
+#[derive(Default, Deref, DerefMut, Deserialize, Resource, Serialize)]
+pub struct PurchasedUpgrades(HashSet<String>);
+impl Saveable for PurchasedUpgrades {
+    fn save(&self, filename: &str) -> Result<()> {
+        format_save(self, filename)
+    }
+
+    fn load(filename: &str) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        format_load(filename)
+    }
+}
+
+/// Combined effect of every purchased upgrade. Applied on top of the base
+/// power components, which are never modified.
+#[derive(Resource)]
+pub struct UpgradeModifiers {
+    production: HashMap<String, f64>,
+    rate: HashMap<String, f64>,
+    click: f64,
+    click_per_second: f64,
+    auto_buyer: bool,
+}
+impl UpgradeModifiers {
+    fn new(upgrades: &Upgrades, purchased: &PurchasedUpgrades) -> Self {
+        use UpgradeEffect::*;
+
+        let mut modifiers = Self {
+            production: HashMap::new(),
+            rate: HashMap::new(),
+            click: 1.0,
+            click_per_second: 0.0,
+            auto_buyer: false,
+        };
+        for upgrade in upgrades.0.iter().filter(|u| purchased.contains(&u.id)) {
+            match &upgrade.effect {
+                Production { power, multiplier } => {
+                    *modifiers.production.entry(power.clone()).or_insert(1.0) *= multiplier;
+                }
+                Rate { power, multiplier } => {
+                    *modifiers.rate.entry(power.clone()).or_insert(1.0) *= multiplier;
+                }
+                Click { multiplier } => modifiers.click *= multiplier,
+                ClickPerSecond { percent } => modifiers.click_per_second += percent / 100.0,
+                AutoBuyer => modifiers.auto_buyer = true,
+            }
+        }
+        modifiers
+    }
+
+    pub fn production(&self, id: &str) -> f64 {
+        self.production.get(id).copied().unwrap_or(1.0)
+    }
+
+    // MULTIPLIES THE PRODUCTION PERIOD, BELOW 1.0 IS FASTER
+    pub fn rate(&self, id: &str) -> f64 {
+        self.rate.get(id).copied().unwrap_or(1.0)
+    }
+
+    pub fn click(&self) -> f64 {
+        self.click
+    }
+
+    // FRACTION OF PWR/S ADDED TO EVERY CLICK
+    pub fn click_per_second(&self) -> f64 {
+        self.click_per_second
+    }
+
+    pub fn auto_buyer(&self) -> bool {
+        self.auto_buyer
+    }
+}
+
+// AN UNKNOWN POWER NEVER UNLOCKS THE UPGRADE OR TAKES ITS EFFECT
+fn validate_upgrades(upgrades: Res<Upgrades>, economy: Res<GameEconomy>) {
+    for upgrade in upgrades.0.iter() {
+        let effect = match &upgrade.effect {
+            UpgradeEffect::Production { power, .. } | UpgradeEffect::Rate { power, .. } => {
+                Some(power.as_str())
+            }
+            _ => None,
+        };
+        for power in upgrade.unlock.powers().into_iter().chain(effect) {
+            if economy.power(power).is_none() {
+                info!(
+                    "[ERROR] Upgrade {} References An Unknown Power: {}",
+                    upgrade.id, power
+                );
+            }
+        }
+    }
+}
+
+fn startup(mut commands: Commands) {
+    commands.spawn((
+        UpgradeListNode::node(),
+        UpgradeListNode::marker(),
+        UpgradeList::default(),
+        CleanupUpgrades,
+    ));
+    info!("[SPAWNED] Upgrade List");
+}
+
+fn cleanup(mut commands: Commands, query_entity: Query<Entity, With<CleanupUpgrades>>) {
+    for entity in query_entity.iter() {
+        commands.entity(entity).despawn_recursive();
+        info!("[DESPAWNED] Upgrade List");
+    }
+}
+
+fn upgrade_label(upgrade: &Upgrade, economy: &GameEconomy) -> String {
+    let label = format!(
+        "{}\n{}\nCOST: {}",
+        upgrade.title,
+        upgrade.effect.description(economy),
+        upgrade.cost
+    );
+    if upgrade.permanent {
+        format!("{}\nPERMANENT", label)
+    } else {
+        label
+    }
+}
+
+fn refresh_upgrade_list(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    upgrades: Res<Upgrades>,
+    purchased: Res<PurchasedUpgrades>,
+    economy: Res<GameEconomy>,
+    mut query_list: Query<(Entity, &mut UpgradeList)>,
+) {
+    let Ok((entity, mut list)) = query_list.get_single_mut() else {
+        return;
+    };
+
+    let available: Vec<&Upgrade> = upgrades
+        .0
+        .iter()
+        .filter(|u| !purchased.contains(&u.id) && economy.condition_met(&u.unlock))
+        .collect();
+
+    if available.iter().map(|u| &u.id).eq(list.0.iter()) {
+        return;
+    }
+    list.0 = available.iter().map(|u| u.id.clone()).collect();
+
+    let font = asset_server.load("fonts/PublicPixel.ttf");
+    let style = (
+        BorderColor(Pallette::Black.srgb()),
+        BorderRadius::all(Val::Percent(10.0)),
+        BackgroundColor(Pallette::Lighter.srgb()),
+    );
+
+    commands
+        .entity(entity)
+        .despawn_descendants()
+        .with_children(|parent| {
+            for upgrade in available {
+                parent
+                    .spawn((
+                        UpgradeButtonNode::node(),
+                        UpgradeButtonNode::marker(),
+                        Button,
+                        UIButton,
+                        UpgradeButton(upgrade.id.clone()),
+                        style,
+                    ))
+                    .with_child((
+                        Text::new(upgrade_label(upgrade, &economy)),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 10.0,
+                            ..default()
+                        },
+                        TextColor(Pallette::Black.srgb()),
+                    ));
+            }
+        });
+}
+
+fn upgrade_click(
+    upgrades: Res<Upgrades>,
+    mut purchased: ResMut<PurchasedUpgrades>,
+    mut economy: ResMut<GameEconomy>,
+    mut statistics: ResMut<Statistics>,
+    query_interaction: Query<(&Interaction, &UpgradeButton), Changed<Interaction>>,
+) {
+    for (interaction, button) in &query_interaction {
+        if *interaction == Interaction::Pressed {
+            let Some(upgrade) = upgrades.0.iter().find(|u| u.id == button.0) else {
+                continue;
+            };
+            if purchased.contains(&upgrade.id) {
+                continue;
+            }
+            if economy.spend(upgrade.cost) {
+                statistics.record_purchase(upgrade.cost);
+                purchased.insert(upgrade.id.clone());
+                info!("[MODIFIED] Upgrade Purchased: {}", upgrade.title);
+            } else {
+                info!("[INVALID] Insufficient Power");
+            }
+        }
+    }
+}
+
+fn reset_upgrades(
+    mut evr_reset_upgrades: EventReader<ResetUpgrades>,
+    upgrades: Res<Upgrades>,
+    mut purchased: ResMut<PurchasedUpgrades>,
+    mut evw_save: EventWriter<Save>,
+) {
+    for _ev in evr_reset_upgrades.read() {
+        purchased.retain(|id| upgrades.0.iter().any(|u| u.id == *id && u.permanent));
+        // THE REBIRTH'S OWN SAVE MAY HAVE RUN BEFORE THIS
+        evw_save.send(Save);
+        info!(
+            "[MODIFIED] Upgrades Reset, {} Permanent Kept",
+            purchased.len()
+        );
+    }
+}
+
+fn update_modifiers(
+    upgrades: Res<Upgrades>,
+    purchased: Res<PurchasedUpgrades>,
+    mut modifiers: ResMut<UpgradeModifiers>,
+) {
+    if purchased.is_changed() {
+        *modifiers = UpgradeModifiers::new(&upgrades, &purchased);
+    }
+}
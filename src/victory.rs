@@ -0,0 +1,449 @@
+use bevy::{prelude::*, scene::ron::de::from_str};
+use serde::{Deserialize, Serialize};
+use std::io::Result;
+
+use crate::{
+    achievements::Achievements,
+    game::GameEconomy,
+    number::clock,
+    prestige::Prestige,
+    save::{format_load, format_save, Save, Saveable},
+    ui::{
+        CreditsRollNode, DialogParentNode, Pallette, UIButton, UIButtonChildNode,
+        UIButtonParentNode,
+    },
+    unlock::UnlockCondition,
+    upgrades::ResetUpgrades,
+    AppState, PauseState,
+};
+
+// HOW LONG EACH PART OF THE ENDING STAYS UP UNLESS SKIPPED
+const ENDING_LINE_SECS: f32 = 4.0;
+const STATS_SECS: f32 = 10.0;
+const CREDITS_SECS: f32 = 20.0;
+
+pub struct VictoryPlugin;
+impl Plugin for VictoryPlugin {
+    fn name(&self) -> &str {
+        "Victory Plugin"
+    }
+
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::Victory), startup)
+            .add_systems(OnExit(AppState::Victory), cleanup)
+            .add_systems(
+                Update,
+                (
+                    advance_sequence,
+                    show_stage,
+                    roll_credits,
+                    victory_button_interaction,
+                )
+                    .chain()
+                    .run_if(in_state(AppState::Victory)),
+            )
+            .add_systems(Update, check_victory.run_if(in_state(PauseState::Unpaused)))
+            .insert_resource(VictoryDefinition::from_ron(include_str!(
+                "../assets/data/victory.ron"
+            )))
+            .insert_resource(Victory::load("victory.ron").unwrap_or_default());
+    }
+}
+
+#[derive(Component)]
+struct CleanupVictory;
+
+#[derive(Component)]
+enum VictoryMenuButton {
+    NewGamePlus,
+    Continue,
+}
+
+/// The goal that ends a run, from `assets/data/victory.ron`.
+#[derive(Default, Deserialize, Resource)]
+struct VictoryDefinition {
+    condition: UnlockCondition,
+    ending: Vec<String>,
+}
+impl VictoryDefinition {
+    fn from_ron(s: &str) -> Self {
+        match from_str::<VictoryDefinition>(s) {
+            Ok(definition) => {
+                info!("[LOADED] Victory Definition");
+                definition
+            }
+            Err(e) => {
+                info!("[ERROR] Victory Definition Failed To Parse: {}", e);
+                Self::default()
+            }
+        }
+    }
+}
+
+/// Which runs have been won, and how many New Game+ cycles were started.
+#[derive(Default, Deserialize, Resource, Serialize)]
+#[serde(default)]
+pub struct Victory {
+    // A RUN IS IDENTIFIED BY THE REBIRTH COUNT IT STARTED ON
+    won_run: Option<u32>,
+    wins: u32,
+    new_game_plus: u32,
+}
+impl Saveable for Victory {
+    fn save(&self, filename: &str) -> Result<()> {
+        format_save(self, filename)
+    }
+
+    fn load(filename: &str) -> Result<Self>
+    where
+        Self: Sized,
+    {
+        format_load(filename)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum VictoryStage {
+    Ending(usize),
+    Stats,
+    Credits,
+    Choice,
+}
+
+#[derive(Component)]
+struct StageNode(VictoryStage);
+
+#[derive(Resource)]
+struct VictorySequence {
+    stage: VictoryStage,
+    timer: Timer,
+    ending_lines: usize,
+}
+impl VictorySequence {
+    fn new(ending_lines: usize) -> Self {
+        let stage = match ending_lines {
+            0 => VictoryStage::Stats,
+            _ => VictoryStage::Ending(0),
+        };
+        Self {
+            stage,
+            timer: Self::timer(stage),
+            ending_lines,
+        }
+    }
+
+    fn timer(stage: VictoryStage) -> Timer {
+        let secs = match stage {
+            VictoryStage::Ending(_) => ENDING_LINE_SECS,
+            VictoryStage::Stats => STATS_SECS,
+            VictoryStage::Credits => CREDITS_SECS,
+            VictoryStage::Choice => 0.0,
+        };
+        Timer::from_seconds(secs, TimerMode::Once)
+    }
+
+    fn advance(&mut self) {
+        use VictoryStage::*;
+
+        self.stage = match self.stage {
+            Ending(line) if line + 1 < self.ending_lines => Ending(line + 1),
+            Ending(_) => Stats,
+            Stats => Credits,
+            Credits | Choice => Choice,
+        };
+        self.timer = Self::timer(self.stage);
+    }
+}
+
+fn check_victory(
+    definition: Res<VictoryDefinition>,
+    economy: Res<GameEconomy>,
+    prestige: Res<Prestige>,
+    mut victory: ResMut<Victory>,
+    mut evw_save: EventWriter<Save>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    // CONTINUING A WON RUN NEVER ENDS IT AGAIN
+    if victory.won_run == Some(prestige.rebirths()) {
+        return;
+    }
+    if economy.condition_met(&definition.condition) {
+        victory.won_run = Some(prestige.rebirths());
+        victory.wins += 1;
+        evw_save.send(Save);
+        next_state.set(AppState::Victory);
+        info!("[UNLOCKED] Victory: Win {}", victory.wins);
+        info!("[MODIFIED] AppState >> Victory");
+    }
+}
+
+fn startup(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    definition: Res<VictoryDefinition>,
+    economy: Res<GameEconomy>,
+    prestige: Res<Prestige>,
+    achievements: Res<Achievements>,
+    victory: Res<Victory>,
+) {
+    let font = asset_server.load("fonts/PublicPixel.ttf");
+
+    let style = (
+        BorderColor(Pallette::Black.srgb()),
+        BorderRadius::all(Val::Percent(10.0)),
+        BackgroundColor(Pallette::Lighter.srgb()),
+    );
+
+    commands.insert_resource(VictorySequence::new(definition.ending.len()));
+
+    // SPAWN ENDING, ONE LINE PER STAGE
+    for (line, text) in definition.ending.iter().enumerate() {
+        commands
+            .spawn((
+                DialogParentNode::node(),
+                DialogParentNode::marker(),
+                Visibility::Hidden,
+                StageNode(VictoryStage::Ending(line)),
+                CleanupVictory,
+            ))
+            .with_child((
+                Text::new(text),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Pallette::White.srgb()),
+                TextLayout::new_with_justify(JustifyText::Center),
+            ));
+    }
+
+    // SPAWN RUN STATISTICS
+    let owned: i64 = economy.powers().iter().map(|power| power.owned()).sum();
+    commands
+        .spawn((
+            DialogParentNode::node(),
+            DialogParentNode::marker(),
+            Visibility::Hidden,
+            StageNode(VictoryStage::Stats),
+            CleanupVictory,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("RUN COMPLETE"),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 30.0,
+                    ..default()
+                },
+                TextColor(Pallette::White.srgb()),
+            ));
+            parent.spawn((
+                Text::new(format!(
+                    "TIME: {}\nEARNED THIS RUN: {}\nEARNED LIFETIME: {}\nPOWERS OWNED: {}\nREBIRTHS: {}\nACHIEVEMENTS: {}\nWINS: {}\nNEW GAME+: {}",
+                    clock(prestige.run_secs()),
+                    prestige.run_earned(),
+                    prestige.lifetime_earned(),
+                    owned,
+                    prestige.rebirths(),
+                    achievements.unlocked().len(),
+                    victory.wins,
+                    victory.new_game_plus
+                )),
+                TextFont {
+                    font: font.clone(),
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Pallette::Lighter.srgb()),
+                TextLayout::new_with_justify(JustifyText::Center),
+            ));
+        });
+
+    // SPAWN CREDITS, SCROLLED UP THE SCREEN BY roll_credits
+    commands
+        .spawn((
+            CreditsRollNode::node(),
+            CreditsRollNode::marker(),
+            Visibility::Hidden,
+            StageNode(VictoryStage::Credits),
+            CleanupVictory,
+        ))
+        .with_children(|parent| {
+            for (line, heading) in credit_lines(include_str!("../credits/CREDITS.md")) {
+                let (font_size, color) = if heading {
+                    (30.0, Pallette::White.srgb())
+                } else {
+                    (14.0, Pallette::Lighter.srgb())
+                };
+                parent.spawn((
+                    Text::new(line),
+                    TextFont {
+                        font: font.clone(),
+                        font_size,
+                        ..default()
+                    },
+                    TextColor(color),
+                ));
+            }
+        });
+
+    // SPAWN NEW GAME+ AND CONTINUE BUTTONS
+    commands
+        .spawn((
+            UIButtonParentNode::new(100.0, 20.0, 40.0),
+            UIButtonParentNode::marker(),
+            Visibility::Hidden,
+            StageNode(VictoryStage::Choice),
+            CleanupVictory,
+        ))
+        .with_children(|parent| {
+            for (button, label) in [
+                (VictoryMenuButton::NewGamePlus, "NEW GAME+"),
+                (VictoryMenuButton::Continue, "CONTINUE"),
+            ] {
+                parent
+                    .spawn((
+                        UIButtonChildNode::node(),
+                        UIButtonChildNode::marker(),
+                        Button,
+                        button,
+                        UIButton,
+                        style,
+                    ))
+                    .with_child((
+                        Text::new(label),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: 25.0,
+                            ..default()
+                        },
+                        TextColor(Pallette::Black.srgb()),
+                    ));
+            }
+        });
+
+    info!("[SPAWNED] Victory Entities.");
+}
+
+fn cleanup(mut commands: Commands, query_cleanup: Query<Entity, With<CleanupVictory>>) {
+    for entity in query_cleanup.iter() {
+        commands.entity(entity).despawn_recursive();
+        info!("[DESPAWNED] Victory Entities");
+    }
+    commands.remove_resource::<VictorySequence>();
+}
+
+// EACH STAGE MOVES ON BY ITSELF, OR EARLY ON A CLICK OR KEY PRESS
+fn advance_sequence(
+    time: Res<Time>,
+    mouse: Res<ButtonInput<MouseButton>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut sequence: ResMut<VictorySequence>,
+) {
+    if sequence.stage == VictoryStage::Choice {
+        return;
+    }
+    let skipped = mouse.just_pressed(MouseButton::Left)
+        || keys.any_just_pressed([KeyCode::Space, KeyCode::Enter, KeyCode::Escape]);
+    if sequence.timer.tick(time.delta()).just_finished() || skipped {
+        sequence.advance();
+    }
+}
+
+fn show_stage(
+    sequence: Res<VictorySequence>,
+    mut query_stage: Query<(&StageNode, &mut Visibility)>,
+) {
+    if sequence.is_changed() {
+        for (stage_node, mut visibility) in &mut query_stage {
+            let shown = if stage_node.0 == sequence.stage {
+                Visibility::Visible
+            } else {
+                Visibility::Hidden
+            };
+            visibility.set_if_neq(shown);
+        }
+    }
+}
+
+// SCROLL FROM BELOW THE BOTTOM EDGE TO ABOVE THE TOP OVER THE CREDITS STAGE
+fn roll_credits(
+    sequence: Res<VictorySequence>,
+    mut query_credits: Query<&mut Node, With<CreditsRollNode>>,
+) {
+    if sequence.stage != VictoryStage::Credits {
+        return;
+    }
+    for mut node in &mut query_credits {
+        node.top = Val::Percent(100.0 - 200.0 * sequence.timer.fraction());
+    }
+}
+
+fn victory_button_interaction(
+    mut prestige: ResMut<Prestige>,
+    mut economy: ResMut<GameEconomy>,
+    mut evw_reset_upgrades: EventWriter<ResetUpgrades>,
+    mut victory: ResMut<Victory>,
+    mut evw_save: EventWriter<Save>,
+    mut next_state: ResMut<NextState<AppState>>,
+    interaction_query: Query<(&Interaction, &VictoryMenuButton), Changed<Interaction>>,
+) {
+    use VictoryMenuButton::*;
+
+    for (interaction, vmb) in &interaction_query {
+        if *interaction == Interaction::Pressed {
+            match vmb {
+                NewGamePlus => {
+                    // A REBIRTH THAT ALWAYS GOES THROUGH, PAYING OUT WHATEVER THE RUN EARNED
+                    let gain = prestige.rebirth();
+                    economy.reset_run(prestige.head_start());
+                    evw_reset_upgrades.send(ResetUpgrades);
+                    victory.new_game_plus += 1;
+                    evw_save.send(Save);
+                    info!(
+                        "[MODIFIED] New Game+ {}: +{} Prestige",
+                        victory.new_game_plus, gain
+                    );
+                }
+                Continue => info!("[MODIFIED] Continuing Won Run"),
+            }
+            next_state.set(AppState::Playing);
+            info!("[MODIFIED] AppState >> Playing");
+        }
+    }
+}
+
+// MARKDOWN DOWN TO PLAIN LINES: HEADINGS ARE FLAGGED, LIST MARKERS AND LINK TARGETS DROPPED
+fn credit_lines(markdown: &str) -> Vec<(String, bool)> {
+    markdown
+        .lines()
+        .map(|line| {
+            let line = line.trim();
+            match line.strip_prefix('#') {
+                Some(heading) => (heading.trim_start_matches('#').trim().to_uppercase(), true),
+                None => {
+                    let item = line.trim_start_matches(['*', '-']).trim();
+                    (strip_links(item.trim_end_matches(';')), false)
+                }
+            }
+        })
+        .collect()
+}
+
+fn strip_links(mut line: &str) -> String {
+    let mut plain = String::new();
+    while let Some(open) = line.find('[') {
+        let Some(middle) = line[open..].find("](").map(|i| open + i) else {
+            break;
+        };
+        let Some(close) = line[middle..].find(')').map(|i| middle + i) else {
+            break;
+        };
+        plain.push_str(&line[..open]);
+        plain.push_str(&line[open + 1..middle]);
+        line = &line[close + 1..];
+    }
+    plain.push_str(line);
+    plain
+}
@@ -0,0 +1,388 @@
+use bevy::{prelude::*, scene::ron::de::from_str};
+use rand::{distributions::WeightedIndex, prelude::*};
+use serde::Deserialize;
+
+use crate::{
+    click::{AddClickBuff, ClickValue},
+    game::{EarnedFrom, GameEconomy, PowerEarned, PowerPerSecond},
+    ui::{Pallette, RestoreButtonNode, SparkNode, Toast, UIButton},
+    AppState, CurrentOwned, GameRng, PauseState, Title,
+};
+
+pub struct WorldEventPlugin;
+impl Plugin for WorldEventPlugin {
+    fn name(&self) -> &str {
+        "World Event Plugin"
+    }
+
+    fn build(&self, app: &mut App) {
+        app.add_event::<StartWorldEvent>()
+            .init_resource::<WorldModifiers>()
+            .insert_resource(WorldEvents::from_ron(include_str!(
+                "../assets/data/world_events.ron"
+            )))
+            .add_systems(OnExit(AppState::Playing), cleanup)
+            .add_systems(
+                Update,
+                (
+                    (schedule_world_events, start_world_event).chain(),
+                    tick_world_modifiers,
+                    drift_sparks,
+                    spark_click,
+                    restore_click,
+                )
+                    .run_if(in_state(PauseState::Unpaused)),
+            );
+    }
+}
+
+#[derive(Component)]
+struct CleanupWorldEvents;
+
+/// Marks a power that has stopped producing until the player restores it.
+#[derive(Component)]
+pub struct Blackout;
+
+#[derive(Component)]
+struct RestoreButton(Entity);
+
+#[derive(Component)]
+struct Spark {
+    reward_secs: f64,
+    // BOTH IN PERCENT OF THE WINDOW
+    position: Vec2,
+    velocity: Vec2,
+    lifetime: Timer,
+}
+
+#[derive(Clone, Deserialize)]
+enum WorldEventKind {
+    Surge {
+        multiplier: f64,
+        secs: f32,
+    },
+    Blackout,
+    Spark {
+        reward_secs: f64,
+        lifetime_secs: f32,
+    },
+    Frenzy {
+        multiplier: f64,
+        secs: f32,
+    },
+}
+
+#[derive(Deserialize)]
+struct WorldEventEntry {
+    weight: u32,
+    kind: WorldEventKind,
+}
+
+#[derive(Default, Deserialize)]
+struct WorldEventTable {
+    min_interval_secs: f32,
+    max_interval_secs: f32,
+    events: Vec<WorldEventEntry>,
+}
+impl WorldEventTable {
+    // TIMERS PANIC ON NEGATIVE OR NAN SECONDS, SO EVERY NUMBER MUST BE FINITE AND ABOVE ZERO
+    fn invalid(&self) -> Option<(&'static str, f64)> {
+        let mut values = vec![
+            ("min_interval_secs", self.min_interval_secs as f64),
+            ("max_interval_secs", self.max_interval_secs as f64),
+        ];
+        for entry in self.events.iter() {
+            match entry.kind {
+                WorldEventKind::Surge { multiplier, secs } => {
+                    values.push(("Surge multiplier", multiplier));
+                    values.push(("Surge secs", secs as f64));
+                }
+                WorldEventKind::Blackout => {}
+                WorldEventKind::Spark {
+                    reward_secs,
+                    lifetime_secs,
+                } => {
+                    values.push(("Spark reward_secs", reward_secs));
+                    values.push(("Spark lifetime_secs", lifetime_secs as f64));
+                }
+                WorldEventKind::Frenzy { multiplier, secs } => {
+                    values.push(("Frenzy multiplier", multiplier));
+                    values.push(("Frenzy secs", secs as f64));
+                }
+            }
+        }
+        values
+            .into_iter()
+            .find(|(_, value)| !value.is_finite() || *value <= 0.0)
+    }
+}
+
+#[derive(Event)]
+struct StartWorldEvent(WorldEventKind);
+
+/// The event table from `assets/data/world_events.ron` and the countdown to the next roll.
+#[derive(Resource)]
+struct WorldEvents {
+    table: WorldEventTable,
+    timer: Timer,
+}
+impl WorldEvents {
+    fn from_ron(s: &str) -> Self {
+        let table = match from_str::<WorldEventTable>(s) {
+            Ok(table) => match table.invalid() {
+                Some((field, value)) => {
+                    info!("[ERROR] World Events Invalid {}: {}", field, value);
+                    WorldEventTable::default()
+                }
+                None => {
+                    info!("[LOADED] World Events: {}", table.events.len());
+                    table
+                }
+            },
+            Err(e) => {
+                info!("[ERROR] World Events Failed To Parse: {}", e);
+                WorldEventTable::default()
+            }
+        };
+        // THE FIRST ROLL WAITS THE LONGEST INTERVAL
+        let timer = Timer::from_seconds(table.max_interval_secs.max(1.0), TimerMode::Once);
+        Self { table, timer }
+    }
+
+    fn next_interval(&self, rng: &mut GameRng) -> f32 {
+        let (min, max) = (
+            self.table.min_interval_secs.max(1.0),
+            self.table.max_interval_secs.max(1.0),
+        );
+        if max > min {
+            rng.gen_range(min..max)
+        } else {
+            min
+        }
+    }
+}
+
+/// Global production bonuses from events that are still running.
+#[derive(Default, Resource)]
+pub struct WorldModifiers {
+    surges: Vec<(f64, Timer)>,
+}
+impl WorldModifiers {
+    pub fn production(&self) -> f64 {
+        self.surges
+            .iter()
+            .map(|(multiplier, _)| multiplier)
+            .product()
+    }
+}
+
+fn cleanup(
+    mut commands: Commands,
+    query_entity: Query<Entity, With<CleanupWorldEvents>>,
+    query_blackout: Query<Entity, With<Blackout>>,
+) {
+    for entity in query_entity.iter() {
+        commands.entity(entity).despawn_recursive();
+        info!("[DESPAWNED] World Event Entities");
+    }
+    // NOBODY IS LEFT TO CLICK RESTORE, SO LIGHTS COME BACK ON
+    for entity in query_blackout.iter() {
+        commands.entity(entity).remove::<Blackout>();
+    }
+}
+
+fn schedule_world_events(
+    time: Res<Time>,
+    mut rng: ResMut<GameRng>,
+    mut world_events: ResMut<WorldEvents>,
+    mut evw_start_world_event: EventWriter<StartWorldEvent>,
+) {
+    if !world_events.timer.tick(time.delta()).finished() {
+        return;
+    }
+
+    let interval = world_events.next_interval(&mut rng);
+    world_events.timer = Timer::from_seconds(interval, TimerMode::Once);
+
+    let weights = world_events.table.events.iter().map(|entry| entry.weight);
+    let Ok(index) = WeightedIndex::new(weights) else {
+        info!("[ERROR] World Events Have No Weight");
+        return;
+    };
+    let kind = world_events.table.events[index.sample(&mut **rng)]
+        .kind
+        .clone();
+    evw_start_world_event.send(StartWorldEvent(kind));
+}
+
+fn start_world_event(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut rng: ResMut<GameRng>,
+    mut world_modifiers: ResMut<WorldModifiers>,
+    mut evr_start_world_event: EventReader<StartWorldEvent>,
+    mut evw_toast: EventWriter<Toast>,
+    mut evw_add_click_buff: EventWriter<AddClickBuff>,
+    query_power: Query<(Entity, &Title, &CurrentOwned)>,
+    query_blackout: Query<(), With<Blackout>>,
+) {
+    for ev in evr_start_world_event.read() {
+        match ev.0.clone() {
+            WorldEventKind::Surge { multiplier, secs } => {
+                world_modifiers
+                    .surges
+                    .push((multiplier, Timer::from_seconds(secs, TimerMode::Once)));
+                evw_toast.send(Toast(format!(
+                    "POWER SURGE! x{} PROD FOR {}s",
+                    multiplier, secs
+                )));
+                info!("[EVENT] Surge: x{} For {}s", multiplier, secs);
+            }
+            WorldEventKind::Blackout => {
+                // ONE BLACKOUT AT A TIME, AND ONLY ON A POWER THAT IS PRODUCING
+                if !query_blackout.is_empty() {
+                    continue;
+                }
+                let candidates: Vec<(Entity, &Title)> = query_power
+                    .iter()
+                    .filter(|(_, _, current_owned)| current_owned.0 > 0)
+                    .map(|(entity, title, _)| (entity, title))
+                    .collect();
+                let Some((entity, title)) = candidates.choose(&mut **rng) else {
+                    continue;
+                };
+
+                commands.entity(*entity).insert(Blackout);
+                commands
+                    .spawn((
+                        RestoreButtonNode::node(),
+                        RestoreButtonNode::marker(),
+                        Button,
+                        UIButton,
+                        RestoreButton(*entity),
+                        BorderColor(Pallette::Black.srgb()),
+                        BorderRadius::all(Val::Percent(10.0)),
+                        BackgroundColor(Pallette::Lighter.srgb()),
+                        GlobalZIndex(50),
+                        CleanupWorldEvents,
+                    ))
+                    .with_child((
+                        Text::new(format!("BLACKOUT!\nRESTORE {}", title.0)),
+                        TextFont {
+                            font: asset_server.load("fonts/PublicPixel.ttf"),
+                            font_size: 14.0,
+                            ..default()
+                        },
+                        TextColor(Pallette::Black.srgb()),
+                    ));
+                evw_toast.send(Toast(format!("BLACKOUT! {} IS DOWN", title.0)));
+                info!("[EVENT] Blackout: {}", title.0);
+            }
+            WorldEventKind::Spark {
+                reward_secs,
+                lifetime_secs,
+            } => {
+                // ENTER OFF THE LEFT EDGE AND DRIFT ACROSS THE ROOM
+                let position = Vec2::new(-5.0, rng.gen_range(20.0..70.0));
+                let velocity = Vec2::new(110.0 / lifetime_secs.max(1.0), rng.gen_range(-3.0..3.0));
+                commands.spawn((
+                    SparkNode::node(position),
+                    SparkNode::marker(),
+                    Button,
+                    BorderColor(Pallette::Black.srgb()),
+                    BorderRadius::MAX,
+                    BackgroundColor(Pallette::White.srgb()),
+                    GlobalZIndex(50),
+                    Spark {
+                        reward_secs,
+                        position,
+                        velocity,
+                        lifetime: Timer::from_seconds(lifetime_secs, TimerMode::Once),
+                    },
+                    CleanupWorldEvents,
+                ));
+                evw_toast.send(Toast("A SPARK IS LOOSE! CATCH IT!".to_string()));
+                info!("[EVENT] Spark");
+            }
+            WorldEventKind::Frenzy { multiplier, secs } => {
+                evw_add_click_buff.send(AddClickBuff { multiplier, secs });
+                evw_toast.send(Toast(format!(
+                    "CLICK FRENZY! x{} CLICKS FOR {}s",
+                    multiplier, secs
+                )));
+                info!("[EVENT] Frenzy: x{} For {}s", multiplier, secs);
+            }
+        }
+    }
+}
+
+fn tick_world_modifiers(time: Res<Time>, mut world_modifiers: ResMut<WorldModifiers>) {
+    if world_modifiers.surges.is_empty() {
+        return;
+    }
+    for (_, timer) in world_modifiers.surges.iter_mut() {
+        timer.tick(time.delta());
+    }
+    world_modifiers
+        .surges
+        .retain(|(_, timer)| !timer.finished());
+}
+
+fn drift_sparks(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query_spark: Query<(Entity, &mut Spark, &mut Node)>,
+) {
+    for (entity, mut spark, mut node) in query_spark.iter_mut() {
+        if spark.lifetime.tick(time.delta()).finished() {
+            commands.entity(entity).despawn_recursive();
+            info!("[DESPAWNED] Spark Got Away");
+            continue;
+        }
+        let step = spark.velocity * time.delta_secs();
+        spark.position += step;
+        node.left = Val::Percent(spark.position.x);
+        node.top = Val::Percent(spark.position.y);
+    }
+}
+
+fn spark_click(
+    mut commands: Commands,
+    power_per_second: Res<PowerPerSecond>,
+    click_value: Res<ClickValue>,
+    mut economy: ResMut<GameEconomy>,
+    mut evw_power_earned: EventWriter<PowerEarned>,
+    mut evw_toast: EventWriter<Toast>,
+    query_interaction: Query<(Entity, &Interaction, &Spark), Changed<Interaction>>,
+) {
+    for (entity, interaction, spark) in &query_interaction {
+        if *interaction == Interaction::Pressed {
+            // WORTH A STRETCH OF PRODUCTION, OR OF CLICKING ONCE A SECOND EARLY ON
+            let reward = (**power_per_second * spark.reward_secs)
+                .max(click_value.value() * spark.reward_secs);
+            economy.add_power(reward);
+            evw_power_earned.send(PowerEarned {
+                amount: reward,
+                source: EarnedFrom::Reward,
+            });
+            evw_toast.send(Toast(format!("SPARK CAUGHT! +{}", reward)));
+            commands.entity(entity).despawn_recursive();
+            info!("[MODIFIED] Total Power +{} From Spark", reward);
+        }
+    }
+}
+
+fn restore_click(
+    mut commands: Commands,
+    query_interaction: Query<(Entity, &Interaction, &RestoreButton), Changed<Interaction>>,
+) {
+    for (entity, interaction, restore_button) in &query_interaction {
+        if *interaction == Interaction::Pressed {
+            if let Some(mut power) = commands.get_entity(restore_button.0) {
+                power.remove::<Blackout>();
+            }
+            commands.entity(entity).despawn_recursive();
+            info!("[MODIFIED] Blackout Restored");
+        }
+    }
+}